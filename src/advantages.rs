@@ -1,3 +1,5 @@
+use crate::Skill;
+
 #[derive(
     Debug,
     Copy,
@@ -14,6 +16,8 @@
 pub enum Advantage {
     AbsoluteDirection(AbsoluteDirection),
     AcuteHearing(usize),
+    AcuteTasteSmell(usize),
+    AcuteTouch(usize),
     AcuteVision(usize),
     Ambidexterity,
     AnimalEmpathy,
@@ -26,6 +30,8 @@ pub enum Advantage {
     EasyCasting(usize),
     // Basic Set pg. 51
     EiditicMemory(EiditicMemory),
+    // Basic Set pg. 52, ground movement
+    EnhancedMove(usize),
     Fearless(usize),
     Flexible(Flexible),
     HardToKill(usize),
@@ -70,6 +76,8 @@ impl Advantage {
             // not applied to Bardic Talent
             Self::EasyCasting(level) => *level as i64 * 14,
             Self::EiditicMemory(level) => level.cost(),
+            // 20 points per level for ground movement [BS - 52]
+            Self::EnhancedMove(level) => *level as i64 * 20,
             Self::Flexible(level) => level.cost(),
             // 5 points per level [BS - 59]
             Self::HighManualDexterity(level) => *level as i64 * 5,
@@ -91,6 +99,136 @@ impl Advantage {
             _ => 0,
         }
     }
+
+    /// Modifiers and other mechanical effects of the advantage.
+    pub fn effects(&self) -> Vec<TraitEffect> {
+        match self {
+            Self::AcuteHearing(level) => {
+                vec![TraitEffect::Modifier(RollKind::Hearing, *level as i64)]
+            }
+            Self::AcuteTasteSmell(level) => {
+                vec![TraitEffect::Modifier(RollKind::TasteSmell, *level as i64)]
+            }
+            Self::AcuteTouch(level) => {
+                vec![TraitEffect::Modifier(RollKind::Touch, *level as i64)]
+            }
+            Self::AcuteVision(level) => {
+                vec![TraitEffect::Modifier(RollKind::Vision, *level as i64)]
+            }
+            // [BS - 43]
+            Self::CombatReflexes => vec![
+                TraitEffect::Modifier(RollKind::Defense, 1),
+                TraitEffect::Modifier(RollKind::Surprise, 6),
+                TraitEffect::Modifier(RollKind::FrightCheck, 2),
+                TraitEffect::Modifier(RollKind::Skill(Skill::FastDraw), 1),
+            ],
+            Self::Fearless(level) => {
+                vec![TraitEffect::Modifier(RollKind::FrightCheck, *level as i64)]
+            }
+            Self::HardToKill(level) => {
+                vec![TraitEffect::Modifier(RollKind::DeathCheck, *level as i64)]
+            }
+            Self::HardToSubdue(level) => vec![TraitEffect::Modifier(
+                RollKind::Consciousness,
+                *level as i64,
+            )],
+            // [BS - 74]
+            Self::PerfectBalance => vec![
+                TraitEffect::Modifier(RollKind::Balance, 4),
+                TraitEffect::Modifier(RollKind::Skill(Skill::Acrobatics), 1),
+                TraitEffect::Modifier(RollKind::Skill(Skill::Climbing), 1),
+            ],
+            // [BS - 55]
+            Self::VeryFit => vec![
+                TraitEffect::Modifier(RollKind::DeathCheck, 2),
+                TraitEffect::Modifier(RollKind::Consciousness, 2),
+                TraitEffect::FatigueRecovery(2),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Level of a leveled advantage.
+    pub fn level(&self) -> Option<usize> {
+        match self {
+            Self::AcuteHearing(level)
+            | Self::AcuteTasteSmell(level)
+            | Self::AcuteTouch(level)
+            | Self::AcuteVision(level)
+            | Self::BardicTalent(level)
+            | Self::Charisma(level)
+            | Self::EasyCasting(level)
+            | Self::EnhancedMove(level)
+            | Self::Fearless(level)
+            | Self::HardToKill(level)
+            | Self::HardToSubdue(level)
+            | Self::HighManualDexterity(level)
+            | Self::IndependentIncome(level)
+            | Self::LessSleep(level)
+            | Self::Magery(level)
+            | Self::MusicalAbility(level)
+            | Self::ReducedConsumption(level)
+            | Self::Silence(level)
+            | Self::Status(level)
+            | Self::Striking(level) => Some(*level),
+            _ => None,
+        }
+    }
+}
+
+/// A kind of roll or level that traits can modify.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum RollKind {
+    /// Dodge, Parry and Block.
+    Defense,
+    /// IQ rolls to recover from surprise or mental stun. BS-393
+    Surprise,
+    /// HT rolls to avoid death. BS-419
+    DeathCheck,
+    /// HT rolls to stay conscious. BS-419
+    Consciousness,
+    FrightCheck,
+    Vision,
+    Hearing,
+    TasteSmell,
+    Touch,
+    /// DX rolls to keep one's feet. BS-74
+    Balance,
+    Skill(Skill),
+}
+
+/// What a trait does to the character who has it.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum TraitEffect {
+    #[display("{_1:+} to {_0}")]
+    Modifier(RollKind, i64),
+    /// FP recovered at this multiple of the normal rate.
+    #[display("FP recovery x{_0}")]
+    FatigueRecovery(usize),
 }
 
 #[derive(
@@ -194,6 +332,16 @@ impl Luck {
             Self::Ridiculous => 60,
         }
     }
+
+    /// Real time that must pass between uses. BS-66
+    pub fn cooldown(&self) -> std::time::Duration {
+        let minutes = match self {
+            Self::Normal => 60,
+            Self::Extraordinary => 30,
+            Self::Ridiculous => 10,
+        };
+        std::time::Duration::from_secs(minutes * 60)
+    }
 }
 
 #[derive(
@@ -288,9 +436,15 @@ pub enum Perk {
 )]
 pub enum Disadvantage {
     CodeOfHonor(usize),
+    // Basic Set pg. 130, points
+    Delusion(usize),
     Destiny(usize),
     Duty(Duty),
     Honesty,
+    // Basic Set pg. 148, points
+    Phobia(usize),
+    // Basic Set pg. 162
+    Quirk,
     Selfless,
     SenseOfDuty(SenseOfDuty),
     // Basic Set pg. 155
@@ -302,8 +456,11 @@ pub enum Disadvantage {
 impl Disadvantage {
     pub fn cost(&self) -> i64 {
         match self {
+            Self::Delusion(points) => -(*points as i64),
             Self::Destiny(level) => -(*level as i64),
             Self::Duty(level) => level.cost(),
+            Self::Phobia(points) => -(*points as i64),
+            Self::Quirk => -1,
             Self::Selfless => -5,
             Self::SenseOfDuty(level) => level.cost(),
             // -5 points per level, max level 4 [BS - 155]
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use crate::{BodyArea, DamageType, HitLocation};
+
+/// Low-tech armor from the Basic Set armor table. BS-283
+const CATALOG: &str = include_str!("../data/armor.json");
+
+/// The part of the body an armor piece protects, either a whole area or a single location.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::From,
+)]
+pub enum Coverage {
+    Area(BodyArea),
+    Location(HitLocation),
+}
+
+impl Coverage {
+    pub fn covers(&self, location: &HitLocation) -> bool {
+        match self {
+            Self::Area(area) => location.area() == *area,
+            Self::Location(covered) => covered == location,
+        }
+    }
+}
+
+/// A single piece of armor. BS-282
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct ArmorPiece {
+    name: String,
+    coverage: Vec<Coverage>,
+    dr: usize,
+    /// Flexible armor can be layered under rigid armor, but passes blunt trauma. BS-379
+    flexible: bool,
+    /// Pounds.
+    weight: f64,
+    /// Dollars.
+    cost: usize,
+}
+
+impl ArmorPiece {
+    /// Every armor piece in the bundled catalog.
+    pub fn catalog() -> Result<Vec<Self>, serde_json::Error> {
+        serde_json::from_str(CATALOG)
+    }
+
+    /// The armor piece named `name` in the bundled catalog.
+    pub fn from_catalog(name: &str) -> Option<Self> {
+        Self::catalog()
+            .ok()?
+            .into_iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn covers(&self, location: &HitLocation) -> bool {
+        self.coverage.iter().any(|c| c.covers(location))
+    }
+}
+
+/// Reason an armor piece cannot be worn.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::Error,
+)]
+pub enum ArmorError {
+    /// Nothing can be worn over rigid armor. BS-286
+    #[display("{outer} cannot be worn over the rigid {inner} at {location}")]
+    OverRigid {
+        outer: String,
+        inner: String,
+        location: HitLocation,
+    },
+}
+
+/// Armor worn, innermost layer first.  The DR of layers covering the same location adds. BS-286
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Armor {
+    pieces: Vec<ArmorPiece>,
+}
+
+impl Armor {
+    /// Puts on `piece` over any armor already worn.  Flexible armor may go under rigid armor,
+    /// but nothing may go over rigid armor. BS-286
+    pub fn wear(&mut self, piece: ArmorPiece) -> Result<(), ArmorError> {
+        for location in HitLocation::all().iter().filter(|l| piece.covers(l)) {
+            if let Some(inner) = self
+                .pieces
+                .iter()
+                .find(|p| !p.flexible && p.covers(location))
+            {
+                return Err(ArmorError::OverRigid {
+                    outer: piece.name.clone(),
+                    inner: inner.name.clone(),
+                    location: *location,
+                });
+            }
+        }
+        tracing::trace!("Wearing {}.", piece.name);
+        self.pieces.push(piece);
+        Ok(())
+    }
+
+    /// Takes off the piece named `name`, returning it.
+    pub fn remove(&mut self, name: &str) -> Option<ArmorPiece> {
+        let index = self.pieces.iter().position(|p| p.name == name)?;
+        Some(self.pieces.remove(index))
+    }
+
+    /// Total DR of every layer covering `location`.
+    pub fn dr_at(&self, location: &HitLocation) -> usize {
+        self.pieces
+            .iter()
+            .filter(|p| p.covers(location))
+            .map(|p| p.dr)
+            .sum()
+    }
+
+    /// DR of every hit location.
+    pub fn dr_map(&self) -> BTreeMap<HitLocation, usize> {
+        HitLocation::all()
+            .into_iter()
+            .map(|location| (location, self.dr_at(&location)))
+            .collect()
+    }
+
+    /// Whether `location` is covered only by flexible armor.
+    pub fn is_flexible(&self, location: &HitLocation) -> bool {
+        let mut layers = self.pieces.iter().filter(|p| p.covers(location)).peekable();
+        layers.peek().is_some() && layers.all(|p| p.flexible)
+    }
+
+    /// Pounds.
+    pub fn weight(&self) -> f64 {
+        self.pieces.iter().map(|p| p.weight).sum()
+    }
+
+    /// Dollars.
+    pub fn cost(&self) -> usize {
+        self.pieces.iter().map(|p| p.cost).sum()
+    }
+
+    /// Injury from `damage` of `damage_type` striking `location` through `dr`.  Damage that
+    /// penetrates is multiplied by the wounding modifier, to a minimum of one.  Crushing damage
+    /// stopped by flexible armor still inflicts one point of blunt trauma per full five points
+    /// stopped. BS-379
+    pub fn injury(
+        &self,
+        damage: i64,
+        damage_type: &DamageType,
+        location: &HitLocation,
+        dr: usize,
+    ) -> i64 {
+        let penetrating = damage - dr as i64;
+        if penetrating > 0 {
+            let injury = penetrating as f64 * location.wounding_modifier(damage_type);
+            (injury.floor() as i64).max(1)
+        } else if *damage_type == DamageType::Crushing && self.is_flexible(location) {
+            damage.max(0) / 5
+        } else {
+            0
+        }
+    }
+}
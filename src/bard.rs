@@ -0,0 +1,46 @@
+use crate::{Advantage, Character, Skill};
+
+/// Skills Bardic Talent adds to. Thaumatology-204
+pub const BARDIC_SKILLS: [Skill; 3] = [
+    Skill::MusicalInfluence,
+    Skill::MusicalInstrument,
+    Skill::Singing,
+];
+
+/// Abilities a bard works through song, rolled against Singing or Musical Instrument.
+/// Thaumatology-204
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum BardSong {
+    Empathy,
+    MindProbe,
+    MindReading,
+    Persuade,
+    SpeakWithAnimals,
+}
+
+impl BardSong {
+    /// Level to work the ability: the better of the character's Singing and Musical Instrument,
+    /// with Bardic Talent.  `None` without Bardic Talent.
+    pub fn level(&self, character: &Character) -> Option<i64> {
+        if !character.has(&Advantage::BardicTalent(0)) {
+            return None;
+        }
+        [Skill::Singing, Skill::MusicalInstrument]
+            .iter()
+            .filter_map(|s| character.bardic_level(s))
+            .max()
+    }
+}
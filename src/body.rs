@@ -1,3 +1,7 @@
+use strum::IntoEnumIterator;
+
+use crate::DamageType;
+
 pub trait BodyLocation {
     type Location;
     fn from_roll(roll: usize) -> Self::Location;
@@ -225,3 +229,104 @@ impl BodyLocation for Legs {
         }
     }
 }
+
+/// A specific hit location, naming the area of the body and the part of it struck. BS-398
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::From,
+)]
+pub enum HitLocation {
+    Head(Head),
+    Torso(Torso),
+    Arms(Arms),
+    Legs(Legs),
+}
+
+impl HitLocation {
+    /// Every hit location on the body.
+    pub fn all() -> Vec<Self> {
+        BodyArea::iter().flat_map(|area| area.locations()).collect()
+    }
+
+    /// Location struck given a roll for the area and a roll for the part of that area.
+    pub fn from_rolls(area: usize, part: usize) -> Self {
+        match BodyArea::from_roll(area) {
+            BodyArea::Head => Head::from_roll(part).into(),
+            BodyArea::Torso => Torso::from_roll(part).into(),
+            BodyArea::Arms => Arms::from_roll(part).into(),
+            BodyArea::Legs => Legs::from_roll(part).into(),
+        }
+    }
+
+    pub fn area(&self) -> BodyArea {
+        match self {
+            Self::Head(_) => BodyArea::Head,
+            Self::Torso(_) => BodyArea::Torso,
+            Self::Arms(_) => BodyArea::Arms,
+            Self::Legs(_) => BodyArea::Legs,
+        }
+    }
+
+    pub fn to_hit(&self) -> isize {
+        match self {
+            Self::Head(location) => location.to_hit(),
+            Self::Torso(location) => location.to_hit(),
+            Self::Arms(location) => location.to_hit(),
+            Self::Legs(location) => location.to_hit(),
+        }
+    }
+
+    /// Natural DR of the location, before armor.  The skull has DR 2. BS-399
+    pub fn natural_dr(&self) -> usize {
+        match self {
+            Self::Head(Head::Skull) => 2,
+            _ => 0,
+        }
+    }
+
+    /// Multiplier applied to penetrating damage of `damage_type` striking this location.
+    /// BS-379, BS-399
+    pub fn wounding_modifier(&self, damage_type: &DamageType) -> f64 {
+        let base = damage_type.wounding_modifier();
+        match (self, damage_type) {
+            (Self::Head(Head::Skull | Head::Eyes), _) => 4.0,
+            (Self::Head(Head::Neck), DamageType::Crushing) => 1.5,
+            (Self::Head(Head::Neck), DamageType::Cutting) => 2.0,
+            (
+                Self::Torso(Torso::Vitals),
+                DamageType::Impaling
+                | DamageType::SmallPiercing
+                | DamageType::Piercing
+                | DamageType::LargePiercing
+                | DamageType::HugePiercing,
+            ) => 3.0,
+            (
+                Self::Arms(_) | Self::Legs(_),
+                DamageType::Impaling | DamageType::LargePiercing | DamageType::HugePiercing,
+            ) => 1.0,
+            _ => base,
+        }
+    }
+}
+
+impl BodyArea {
+    /// Every hit location within the area.
+    pub fn locations(&self) -> Vec<HitLocation> {
+        match self {
+            Self::Head => Head::iter().map(HitLocation::from).collect(),
+            Self::Torso => Torso::iter().map(HitLocation::from).collect(),
+            Self::Arms => Arms::iter().map(HitLocation::from).collect(),
+            Self::Legs => Legs::iter().map(HitLocation::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{Outcome, Random, Skill, Spell, SpellClass, SpellDuration, SuccessRoll};
+
+/// Ambient magical energy where a spell is cast. BS-235
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum ManaLevel {
+    /// No spells can be cast.
+    None,
+    /// Spells are at -5.
+    Low,
+    #[default]
+    Normal,
+    High,
+    /// Any failure is a critical failure.
+    VeryHigh,
+}
+
+impl ManaLevel {
+    /// Modifier to spell skill, or `None` where spells cannot be cast.
+    pub fn modifier(&self) -> Option<i64> {
+        match self {
+            Self::None => None,
+            Self::Low => Some(-5),
+            Self::Normal | Self::High | Self::VeryHigh => Some(0),
+        }
+    }
+}
+
+/// Result of a critical failure while casting, by 3d6 roll. BS-236
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Backfire {
+    /// The spell fails and the caster takes 1d of injury.
+    Injury,
+    /// The spell strikes the caster, or a random target if beneficial.
+    OnCaster,
+    /// The spell strikes one of the caster's companions.
+    OnCompanion,
+    /// The spell strikes something other than the intended subject.
+    WrongSubject,
+    /// The spell fails and the caster takes 1 HP of injury.
+    Wound,
+    /// The spell fails and the caster is stunned until they make an IQ roll.
+    Stunned,
+    /// A loud noise, bright flash or awful odor, and nothing else.
+    Noise,
+    /// A weak and useless version of the spell.
+    Weak,
+    /// The reverse of the intended effect.
+    Reversed,
+    /// The spell seems to work, but is only an illusion.
+    Illusion,
+    /// The reverse of the intended effect, on the wrong subject.
+    ReversedWrongSubject,
+    /// The caster forgets the spell for a week.
+    Forgotten,
+    /// Something nasty answers the call.
+    Demon,
+}
+
+impl Backfire {
+    pub fn from_roll(roll: usize) -> Self {
+        match roll {
+            0..=3 => Self::Injury,
+            4 => Self::OnCaster,
+            5..=6 => Self::OnCompanion,
+            7 => Self::WrongSubject,
+            8 => Self::Wound,
+            9 => Self::Stunned,
+            10..=11 => Self::Noise,
+            12 => Self::Weak,
+            13 => Self::Reversed,
+            14 => Self::Illusion,
+            15..=16 => Self::ReversedWrongSubject,
+            17 => Self::Forgotten,
+            _ => Self::Demon,
+        }
+    }
+}
+
+/// Reason a spell cannot be cast.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::Error,
+)]
+pub enum CastingError {
+    #[display("No spells can be cast without mana.")]
+    NoMana,
+    #[display("{_0} has not been learned.")]
+    Unknown(#[error(not(source))] Spell),
+    #[display("{_0} cannot be performed to cast a spell.")]
+    NotMusical(#[error(not(source))] Skill),
+    #[display("Casting a spell requires the Concentrate maneuver.")]
+    NotConcentrating,
+}
+
+/// Modifier to cast an Information spell at `meters`, from the long-distance table. BS-241
+pub fn long_distance_modifier(meters: usize) -> i64 {
+    match meters {
+        0..=200 => 0,
+        201..=800 => -1,
+        801..=1_600 => -2,
+        1_601..=4_800 => -3,
+        4_801..=16_000 => -4,
+        16_001..=48_000 => -5,
+        48_001..=160_000 => -6,
+        160_001..=480_000 => -7,
+        _ => -8,
+    }
+}
+
+/// A spell about to be cast, with where it is aimed.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Casting {
+    spell: Spell,
+    /// Meters to the subject, or to the center of the area.
+    distance: usize,
+    /// Meters of radius for an Area spell, or seconds charged for a Missile spell.
+    size: usize,
+}
+
+impl Casting {
+    /// Modifier to spell skill for distance.  Regular spells are at -1 per meter to the subject.
+    /// Information spells use the long-distance table, and Area and Missile spells have no
+    /// distance penalty to cast. BS-239
+    pub fn modifier(&self) -> i64 {
+        match self.spell.class() {
+            SpellClass::Regular => -(self.distance as i64),
+            SpellClass::Information => long_distance_modifier(self.distance),
+            SpellClass::Area | SpellClass::Missile => 0,
+        }
+    }
+
+    /// Energy to cast at spell `level`, reduced for high skill and by one per level of Easy
+    /// Casting.  Area spells pay per meter of radius, and Missile spells per second charged.
+    /// Thaumatology-28
+    pub fn energy(&self, level: i64, easy_casting: usize) -> usize {
+        let base = self
+            .spell
+            .energy()
+            .at(level)
+            .cast()
+            .saturating_sub(easy_casting);
+        match self.spell.class() {
+            SpellClass::Area | SpellClass::Missile => base * self.size.max(1),
+            SpellClass::Regular | SpellClass::Information => base,
+        }
+    }
+}
+
+/// Outcome of casting a spell.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct CastResult {
+    roll: SuccessRoll,
+    /// Energy spent: none on a critical success, one on an ordinary failure, and the full cost
+    /// otherwise. BS-236
+    energy: usize,
+    backfire: Option<Backfire>,
+}
+
+impl CastResult {
+    /// Rolls to cast at effective skill `effective`, with `energy` as the full cost.  Stable
+    /// Casting turns critical failures into ordinary failures.  In very high mana, every
+    /// failure is critical. Thaumatology-28
+    pub fn resolve(
+        effective: i64,
+        energy: usize,
+        mana: &ManaLevel,
+        stable: bool,
+        random: &mut Random,
+    ) -> Self {
+        let roll = random.success_roll(effective);
+        let critical = match roll.outcome() {
+            Outcome::CriticalFailure => !stable,
+            Outcome::Failure => *mana == ManaLevel::VeryHigh && !stable,
+            _ => false,
+        };
+        let spent = match roll.outcome() {
+            Outcome::CriticalSuccess => 0,
+            Outcome::Failure | Outcome::CriticalFailure if !critical => energy.min(1),
+            _ => energy,
+        };
+        let backfire = critical.then(|| Backfire::from_roll(random.roll()));
+        Self::new(roll, spent, backfire)
+    }
+}
+
+/// A spell kept going past its first duration.  Maintaining is a free action, but each spell
+/// kept on is -1 to cast other spells. BS-237, BS-240
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct MaintainedSpell {
+    spell: Spell,
+    /// Energy to renew the spell for another duration.
+    upkeep: usize,
+    /// Seconds until the spell must be renewed.
+    remaining: usize,
+}
+
+impl MaintainedSpell {
+    /// Length of one duration in seconds, if the spell can be maintained.
+    pub fn period(spell: &Spell) -> Option<usize> {
+        match spell.duration() {
+            SpellDuration::Seconds(seconds) => Some(seconds),
+            SpellDuration::Minutes(minutes) => Some(minutes * 60),
+            SpellDuration::Hours(hours) => Some(hours * 3_600),
+            SpellDuration::Instant | SpellDuration::Permanent => None,
+        }
+    }
+
+    /// Counts down one second.  Returns the upkeep due when a new duration begins.
+    pub fn tick(&mut self) -> Option<usize> {
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.remaining > 0 {
+            return None;
+        }
+        self.remaining = Self::period(&self.spell).unwrap_or_default();
+        Some(self.upkeep)
+    }
+}
@@ -1,8 +1,17 @@
+use std::collections::BTreeMap;
+
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, IntoEnumIterator};
 
-use crate::DieLevel;
+use crate::{
+    Advantage, Armor, ArmorError, ArmorPiece, Carried, CastResult, Casting, CastingError,
+    CombatProfile, DamageType, DieLevel, Disadvantage, Entry, FrightCheck, FrightEffect,
+    HitLocation, Inventory, InventoryError, Item, KnownSkill, KnownSpell, MaintainedSpell,
+    ManaLevel, QuickContest, Random, RollKind, Sense, SenseConditions, Shield, Skill,
+    SpecialFeatures, Spell, SuccessRoll, Talent, TraitEffect, Weapon, BARDIC_SKILLS,
+    FRIGHT_CHECK_MAX,
+};
 
 #[derive(
     Debug,
@@ -168,7 +177,14 @@ impl AttributeColumns {
 }
 
 #[derive(
-    Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize, derive_new::new,
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
 )]
 pub struct Stats {
     /// The maximum weight you can lift over your head with one hand one second.
@@ -199,12 +215,506 @@ impl From<Attributes> for Stats {
     }
 }
 
-pub struct CombatStats {
-    damage_thrust: DamageKind,
-    damage_swing: DamageKind,
-    dr: usize,
-    parry: usize,
-    block: usize,
+/// A player or non-player character, with the attributes they have bought and the advantages,
+/// disadvantages and perks that set them apart.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Character {
+    name: String,
+    attributes: Attributes,
+    /// Secondary characteristics derived from `attributes`.
+    stats: Stats,
+    special_features: SpecialFeatures,
+    skills: Vec<KnownSkill>,
+    spells: Vec<KnownSpell>,
+    /// Spells currently on, renewed at the end of each duration.
+    maintained: Vec<MaintainedSpell>,
+    /// Current hit points and fatigue points.
+    condition: Condition,
+    armor: Armor,
+    inventory: Inventory,
+    /// Weapons in hand, ready to attack or parry.
+    weapons: Vec<Weapon>,
+    shield: Option<Shield>,
+}
+
+impl Character {
+    pub fn new(name: &str, attributes: Attributes, special_features: SpecialFeatures) -> Self {
+        let stats = Stats::from(attributes);
+        let condition = Condition::from(attributes);
+        Self {
+            name: name.to_string(),
+            attributes,
+            stats,
+            special_features,
+            skills: Vec::new(),
+            spells: Vec::new(),
+            maintained: Vec::new(),
+            condition,
+            armor: Armor::default(),
+            inventory: Inventory::default(),
+            weapons: Vec::new(),
+            shield: None,
+        }
+    }
+
+    /// Spends `points` on `skill`, adding to any points already spent on it.
+    pub fn learn(&mut self, skill: Skill, points: usize) {
+        match self.skills.iter_mut().find(|k| *k.skill() == skill) {
+            Some(known) => *known = KnownSkill::new(skill, known.points() + points),
+            None => self.skills.push(KnownSkill::new(skill, points)),
+        }
+    }
+
+    /// Level in `skill`, if the character has learned it.
+    pub fn skill_level(&self, skill: &Skill) -> Option<i64> {
+        self.skills
+            .iter()
+            .find(|k| k.skill() == skill)
+            .and_then(|k| k.level(&self.attributes))
+    }
+
+    /// Spends `points` on `spell`, adding to any points already spent on it.
+    pub fn learn_spell(&mut self, spell: Spell, points: usize) {
+        match self.spells.iter_mut().find(|k| *k.spell() == spell) {
+            Some(known) => *known = KnownSpell::new(spell, known.points() + points),
+            None => self.spells.push(KnownSpell::new(spell, points)),
+        }
+    }
+
+    /// Level in `spell`, including Magery, if the character has learned it.
+    pub fn spell_level(&self, spell: &Spell) -> Option<i64> {
+        let magery = self.advantage_level(&Advantage::Magery(0));
+        self.spells
+            .iter()
+            .find(|k| k.spell() == spell)
+            .and_then(|k| k.level(&self.attributes, magery))
+    }
+
+    /// Casts `casting` in `mana`, spending the energy from FP, then HP once FP runs out.  A
+    /// spell with a duration that succeeds stays on until dropped, renewed each duration. BS-236
+    pub fn cast(
+        &mut self,
+        casting: &Casting,
+        mana: &ManaLevel,
+        random: &mut Random,
+    ) -> Result<CastResult, CastingError> {
+        let spell = casting.spell();
+        let level = self
+            .spell_level(spell)
+            .ok_or(CastingError::Unknown(*spell))?;
+        self.cast_at(casting, level, level, mana, random)
+    }
+
+    /// Casts `casting` as a performance, rolling against `skill` with Bardic Talent instead of
+    /// the spell.  Energy is still figured from the spell's level. Thaumatology-204
+    pub fn perform(
+        &mut self,
+        casting: &Casting,
+        skill: &Skill,
+        mana: &ManaLevel,
+        random: &mut Random,
+    ) -> Result<CastResult, CastingError> {
+        let spell = casting.spell();
+        let level = self
+            .spell_level(spell)
+            .ok_or(CastingError::Unknown(*spell))?;
+        let performance = self
+            .bardic_level(skill)
+            .ok_or(CastingError::NotMusical(*skill))?;
+        self.cast_at(casting, level, performance, mana, random)
+    }
+
+    /// Rolls `casting` against `roll_level`, with energy for a spell at `level`.
+    fn cast_at(
+        &mut self,
+        casting: &Casting,
+        level: i64,
+        roll_level: i64,
+        mana: &ManaLevel,
+        random: &mut Random,
+    ) -> Result<CastResult, CastingError> {
+        let spell = casting.spell();
+        let effective =
+            roll_level + mana.modifier().ok_or(CastingError::NoMana)? + casting.modifier()
+                - self.maintained.len() as i64;
+        let easy_casting = self.advantage_level(&Advantage::EasyCasting(0));
+        let energy = casting.energy(level, easy_casting);
+        let stable = self.has(&Advantage::StableCasting);
+        let result = CastResult::resolve(effective, energy, mana, stable, random);
+        tracing::trace!("{} casts {spell}: {:?}", self.name, result.roll().outcome());
+        self.spend_energy(*result.energy());
+        let upkeep = *spell.energy().at(level).maintain();
+        if let (true, Some(upkeep), Some(period)) = (
+            result.roll().is_success(),
+            upkeep,
+            MaintainedSpell::period(spell),
+        ) {
+            let upkeep = upkeep.saturating_sub(easy_casting);
+            let maintained = MaintainedSpell::new(*spell, upkeep, period);
+            // Casting a spell already kept on starts its duration over.
+            match self.maintained.iter_mut().find(|m| m.spell() == spell) {
+                Some(existing) => *existing = maintained,
+                None => self.maintained.push(maintained),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Stops maintaining `spell`, letting it lapse.
+    pub fn drop_spell(&mut self, spell: &Spell) -> Option<MaintainedSpell> {
+        let index = self.maintained.iter().position(|m| m.spell() == spell)?;
+        Some(self.maintained.remove(index))
+    }
+
+    /// Counts down one second on every spell kept on, paying upkeep for any that begin a new
+    /// duration.  Returns the energy spent.
+    pub fn maintain(&mut self) -> usize {
+        let upkeep = self
+            .maintained
+            .iter_mut()
+            .filter_map(|m| m.tick())
+            .sum::<usize>();
+        self.spend_energy(upkeep);
+        upkeep
+    }
+
+    /// Spends `energy` on magic, from FP while any remain and then from HP. BS-237
+    pub fn spend_energy(&mut self, energy: usize) {
+        let energy = energy as i64;
+        let fp = energy.min(self.condition.fp.max(0));
+        if fp > 0 {
+            self.spend_fatigue(fp);
+        }
+        if energy > fp {
+            self.spend_hit_points(energy - fp);
+        }
+    }
+
+    /// Level in `skill`, or the default from its controlling attribute if it has not been
+    /// learned, plus any talent for it. BS-89, BS-173
+    pub fn effective_skill(&self, skill: &Skill) -> Option<i64> {
+        let level = self.skill_level(skill).or_else(|| {
+            let attribute = self.attributes.value(&skill.attribute()) as i64;
+            skill
+                .difficulty()
+                .default_modifier()
+                .map(|modifier| attribute + modifier)
+        })?;
+        Some(level + self.talent_bonus(skill) + self.roll_modifier(&RollKind::Skill(*skill)))
+    }
+
+    /// Bonus to `skill` from talents.  Talents that overlap do not add together; only the best
+    /// counts.
+    pub fn talent_bonus(&self, skill: &Skill) -> i64 {
+        Talent::iter()
+            .filter(|t| t.covers(skill))
+            .map(|t| t.level(self))
+            .max()
+            .unwrap_or_default() as i64
+    }
+
+    /// Effective level in one of the skills Bardic Talent adds to, with the talent.  `None` for
+    /// any other skill. Thaumatology-204
+    pub fn bardic_level(&self, skill: &Skill) -> Option<i64> {
+        if !BARDIC_SKILLS.contains(skill) {
+            return None;
+        }
+        self.effective_skill(skill)
+    }
+
+    /// Readies `weapon` in hand.
+    pub fn ready(&mut self, weapon: Weapon) {
+        tracing::trace!("{} readies {}.", self.name, weapon.name());
+        self.weapons.push(weapon);
+    }
+
+    /// Puts away the readied weapon named `name`, returning it.
+    pub fn unready(&mut self, name: &str) -> Option<Weapon> {
+        let index = self.weapons.iter().position(|w| w.name() == name)?;
+        Some(self.weapons.remove(index))
+    }
+
+    /// Straps on `shield`, returning any shield already carried.
+    pub fn equip_shield(&mut self, shield: Shield) -> Option<Shield> {
+        self.shield.replace(shield)
+    }
+
+    /// Combat statistics derived from attributes, skills and equipment.
+    pub fn profile(&self) -> CombatProfile {
+        CombatProfile::from(self)
+    }
+
+    /// Loses `fp` fatigue points.
+    pub fn spend_fatigue(&mut self, fp: i64) {
+        self.condition.fp -= fp;
+        tracing::trace!("{} spends {fp} FP, {} left.", self.name, self.condition.fp);
+    }
+
+    /// Loses `hp` hit points.
+    pub fn spend_hit_points(&mut self, hp: i64) {
+        self.condition.hp -= hp;
+        tracing::trace!("{} loses {hp} HP, {} left.", self.name, self.condition.hp);
+    }
+
+    /// Puts on `piece` over any armor already worn.
+    pub fn wear(&mut self, piece: ArmorPiece) -> Result<(), ArmorError> {
+        self.armor.wear(piece)
+    }
+
+    /// DR at `location` from armor and natural toughness.
+    pub fn dr_at(&self, location: &HitLocation) -> usize {
+        location.natural_dr() + self.armor.dr_at(location)
+    }
+
+    /// DR of every hit location.
+    pub fn dr_map(&self) -> BTreeMap<HitLocation, usize> {
+        HitLocation::all()
+            .into_iter()
+            .map(|location| (location, self.dr_at(&location)))
+            .collect()
+    }
+
+    /// Takes `damage` of `damage_type` at `location`, losing hit points for any injury after
+    /// DR and wounding modifiers.  Returns the injury.
+    pub fn injure(&mut self, damage: i64, damage_type: &DamageType, location: &HitLocation) -> i64 {
+        let injury = self
+            .armor
+            .injury(damage, damage_type, location, self.dr_at(location));
+        if injury > 0 {
+            self.spend_hit_points(injury);
+        }
+        injury
+    }
+
+    /// Carries `quantity` of `item`.
+    pub fn carry(&mut self, item: Item, quantity: usize, carried: Carried) {
+        self.inventory.add(item, quantity, carried);
+        tracing::trace!("{} is {} encumbered.", self.name, self.encumbrance_level());
+    }
+
+    /// Drops up to `quantity` of the item named `name`.
+    pub fn drop_item(&mut self, name: &str, quantity: usize) -> Result<Entry, InventoryError> {
+        let dropped = self.inventory.drop(name, quantity)?;
+        tracing::trace!("{} is {} encumbered.", self.name, self.encumbrance_level());
+        Ok(dropped)
+    }
+
+    /// Mutable access to the inventory, for storing items in containers.
+    pub fn inventory_mut(&mut self) -> &mut Inventory {
+        &mut self.inventory
+    }
+
+    /// Pounds carried, including armor worn and weapons in hand.
+    pub fn carried(&self) -> f64 {
+        let weapons = self.weapons.iter().map(|w| w.weight()).sum::<f64>();
+        let shield = self
+            .shield
+            .as_ref()
+            .map(|s| *s.weight())
+            .unwrap_or_default();
+        self.armor.weight() + self.inventory.weight() + weapons + shield
+    }
+
+    /// Encumbrance level from the weight carried. BS-17
+    pub fn encumbrance_level(&self) -> EncumbranceLevel {
+        EncumbranceWeight::from(&self.stats).level(self.carried())
+    }
+
+    /// Move at the current encumbrance level. BS-17
+    pub fn current_move(&self) -> usize {
+        EncumbranceMove::from(&self.stats).at(&self.encumbrance_level())
+    }
+
+    /// Dodge at the current encumbrance level. BS-17
+    pub fn dodge(&self) -> usize {
+        EncumbranceDodge::from(&self.stats).at(&self.encumbrance_level())
+    }
+
+    /// Below 1/3 FP, Move, Dodge and ST are halved. BS-426
+    pub fn is_tired(&self) -> bool {
+        self.condition.fp * 3 < self.attributes.fp as i64
+    }
+
+    /// Whether the character has an advantage of the same kind as `advantage`, at any level.
+    pub fn has(&self, advantage: &Advantage) -> bool {
+        self.advantage(advantage).is_some()
+    }
+
+    /// The character's advantage of the same kind as `advantage`, at whatever level they have it.
+    pub fn advantage(&self, advantage: &Advantage) -> Option<&Advantage> {
+        self.special_features
+            .advantages()
+            .iter()
+            .find(|a| std::mem::discriminant(*a) == std::mem::discriminant(advantage))
+    }
+
+    /// The character's level in a leveled advantage of the same kind as `advantage`, or zero.
+    pub fn advantage_level(&self, advantage: &Advantage) -> usize {
+        self.advantage(advantage)
+            .and_then(|a| a.level())
+            .unwrap_or_default()
+    }
+
+    /// Every mechanical effect of the character's advantages.
+    pub fn trait_effects(&self) -> Vec<TraitEffect> {
+        self.special_features
+            .advantages()
+            .iter()
+            .flat_map(|a| a.effects())
+            .collect()
+    }
+
+    /// Total modifier from traits to rolls of `kind`.
+    pub fn roll_modifier(&self, kind: &RollKind) -> i64 {
+        self.trait_effects()
+            .iter()
+            .filter_map(|e| match e {
+                TraitEffect::Modifier(k, modifier) if k == kind => Some(*modifier),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// Multiple of the normal rate at which the character recovers FP.
+    pub fn fatigue_recovery(&self) -> usize {
+        self.trait_effects()
+            .iter()
+            .filter_map(|e| match e {
+                TraitEffect::FatigueRecovery(rate) => Some(*rate),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// Rests for `minutes`, recovering 1 FP per 10 minutes at the normal rate. BS-427
+    pub fn rest(&mut self, minutes: usize) {
+        let recovered = (minutes * self.fatigue_recovery() / 10) as i64;
+        self.condition.fp = (self.condition.fp + recovered).min(self.attributes.fp as i64);
+    }
+
+    /// HT roll to avoid death at -HP or below. BS-419
+    pub fn death_check(&self, random: &mut Random) -> SuccessRoll {
+        let ht = self.attributes.value(&AttributeType::Health) as i64;
+        random.success_roll(ht + self.roll_modifier(&RollKind::DeathCheck))
+    }
+
+    /// HT roll to stay conscious at 0 HP or below. BS-419
+    pub fn consciousness_check(&self, random: &mut Random) -> SuccessRoll {
+        let ht = self.attributes.value(&AttributeType::Health) as i64;
+        random.success_roll(ht + self.roll_modifier(&RollKind::Consciousness))
+    }
+
+    /// Will roll against fear at `modifier`, no better than 13.  On a failure, rolls on the
+    /// Fright Check table, losing any FP and HP and gaining any mental disadvantage it calls
+    /// for.  A related Phobia growing worse is left to the GM. BS-360
+    pub fn fright_check(&mut self, modifier: i64, random: &mut Random) -> FrightCheck {
+        let will = self.attributes.value(&AttributeType::Willpower) as i64;
+        let level =
+            (will + self.roll_modifier(&RollKind::FrightCheck) + modifier).min(FRIGHT_CHECK_MAX);
+        let roll = random.success_roll(level);
+        if roll.is_success() {
+            return FrightCheck::new(roll, None);
+        }
+        let table = (random.roll() as i64 - roll.margin()).max(0) as usize;
+        let effect = FrightEffect::from_roll(table);
+        tracing::trace!("{} fails a Fright Check: {effect}", self.name);
+        let fatigue = effect.fatigue(random) as i64;
+        if fatigue > 0 {
+            self.spend_fatigue(fatigue);
+        }
+        let ht = self.attributes.value(&AttributeType::Health) as i64;
+        let injury = effect.injury(ht, random) as i64;
+        if injury > 0 {
+            self.spend_hit_points(injury);
+        }
+        let worsens = effect == FrightEffect::WorsePhobia
+            && self
+                .special_features
+                .disadvantages()
+                .iter()
+                .any(|d| matches!(d, Disadvantage::Phobia(_)));
+        if let (Some(disadvantage), false) = (effect.disadvantage(), worsens) {
+            self.special_features.add_disadvantage(disadvantage);
+        }
+        FrightCheck::new(roll, Some(effect))
+    }
+
+    /// Perception plus any acute sense for `sense`. BS-358
+    pub fn sense_level(&self, sense: &Sense) -> i64 {
+        let per = self.attributes.value(&AttributeType::Perception) as i64;
+        per + self.roll_modifier(&sense.roll_kind())
+    }
+
+    /// Rolls `sense` under `conditions`. BS-358
+    pub fn sense_roll(
+        &self,
+        sense: &Sense,
+        conditions: &SenseConditions,
+        random: &mut Random,
+    ) -> SuccessRoll {
+        random.success_roll(self.sense_level(sense) + conditions.modifier(sense))
+    }
+
+    /// Quick Contest of `sense` against the Stealth of `sneaker`, first the character's roll.  If
+    /// the character loses, the sneaker goes unnoticed and may surprise them. BS-222
+    pub fn notice(
+        &self,
+        sneaker: &Character,
+        sense: &Sense,
+        conditions: &SenseConditions,
+        random: &mut Random,
+    ) -> QuickContest {
+        let level = self.sense_level(sense) + conditions.modifier(sense);
+        let stealth = sneaker.effective_skill(&Skill::Stealth).unwrap_or_default();
+        random.quick_contest(level, stealth)
+    }
+
+    /// IQ roll to recover from surprise. BS-393
+    pub fn surprise_check(&self, random: &mut Random) -> SuccessRoll {
+        let iq = self.attributes.value(&AttributeType::Intelligence) as i64;
+        random.success_roll(iq + self.roll_modifier(&RollKind::Surprise))
+    }
+
+    /// DX roll to keep one's feet. BS-74
+    pub fn balance_check(&self, random: &mut Random) -> SuccessRoll {
+        let dx = self.attributes.value(&AttributeType::Dexterity) as i64;
+        random.success_roll(dx + self.roll_modifier(&RollKind::Balance))
+    }
+}
+
+/// Current hit points and fatigue points, which may drop below zero. BS-419, BS-426
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Condition {
+    hp: i64,
+    fp: i64,
+}
+
+impl From<Attributes> for Condition {
+    fn from(attr: Attributes) -> Self {
+        Self::new(attr.hp as i64, attr.fp as i64)
+    }
 }
 
 #[derive(
@@ -225,6 +735,14 @@ pub enum DamageKind {
     Swing(DieLevel),
 }
 
+impl DamageKind {
+    pub fn die_level(&self) -> DieLevel {
+        match self {
+            Self::Thrust(level) | Self::Swing(level) => *level,
+        }
+    }
+}
+
 #[derive(
     Debug,
     Copy,
@@ -236,6 +754,7 @@ pub enum DamageKind {
     Hash,
     serde::Serialize,
     serde::Deserialize,
+    derive_getters::Getters,
     derive_new::new,
 )]
 pub struct BaseDamage {
@@ -322,6 +841,7 @@ impl From<Attributes> for BaseDamage {
     Hash,
     serde::Serialize,
     serde::Deserialize,
+    derive_getters::Getters,
     derive_new::new,
 )]
 pub struct Encumbrance {
@@ -364,6 +884,25 @@ pub struct EncumbranceWeight {
     extra_heavy: usize,
 }
 
+impl EncumbranceWeight {
+    /// Encumbrance level when carrying `weight` pounds.  Past extra-heavy, the character cannot
+    /// move at all, which is treated as extra-heavy. BS-17
+    pub fn level(&self, weight: f64) -> EncumbranceLevel {
+        let weight = weight.ceil() as usize;
+        if weight <= self.none {
+            EncumbranceLevel::None
+        } else if weight <= self.light {
+            EncumbranceLevel::Light
+        } else if weight <= self.medium {
+            EncumbranceLevel::Medium
+        } else if weight <= self.heavy {
+            EncumbranceLevel::Heavy
+        } else {
+            EncumbranceLevel::XHeavy
+        }
+    }
+}
+
 impl From<&Stats> for EncumbranceWeight {
     fn from(stats: &Stats) -> Self {
         let basic_lift = stats.basic_lift;
@@ -403,6 +942,19 @@ pub struct EncumbranceMove {
     extra_heavy: usize,
 }
 
+impl EncumbranceMove {
+    /// Move at the given encumbrance level.
+    pub fn at(&self, level: &EncumbranceLevel) -> usize {
+        match level {
+            EncumbranceLevel::None => self.none,
+            EncumbranceLevel::Light => self.light,
+            EncumbranceLevel::Medium => self.medium,
+            EncumbranceLevel::Heavy => self.heavy,
+            EncumbranceLevel::XHeavy => self.extra_heavy,
+        }
+    }
+}
+
 impl From<&Stats> for EncumbranceMove {
     fn from(stats: &Stats) -> Self {
         let basic_move = stats.basic_move;
@@ -446,6 +998,19 @@ pub struct EncumbranceDodge {
     extra_heavy: usize,
 }
 
+impl EncumbranceDodge {
+    /// Dodge at the given encumbrance level.
+    pub fn at(&self, level: &EncumbranceLevel) -> usize {
+        match level {
+            EncumbranceLevel::None => self.none,
+            EncumbranceLevel::Light => self.light,
+            EncumbranceLevel::Medium => self.medium,
+            EncumbranceLevel::Heavy => self.heavy,
+            EncumbranceLevel::XHeavy => self.extra_heavy,
+        }
+    }
+}
+
 impl From<&Stats> for EncumbranceDodge {
     fn from(stats: &Stats) -> Self {
         // Since basic speed has a minimum of one, at extra heavy usize will not drop below zero.
@@ -466,7 +1031,23 @@ impl From<&Stats> for EncumbranceDodge {
     }
 }
 
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
 pub enum EncumbranceLevel {
+    #[default]
     None,
     Light,
     Medium,
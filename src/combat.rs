@@ -0,0 +1,903 @@
+use std::cmp::Ordering;
+
+use crate::movement::{
+    Action, ActiveDefense, Aim, AllOutAttack, AllOutMeleeAttack, AttackKind, Evaluate, Feint,
+    FreeAction, Grapple, Manuever, ManueverError, MoveAllowance, Posture, Trigger, Wait,
+};
+use crate::{
+    Advantage, AttributeType, CastResult, Casting, CastingError, Character, DamageType, DieLevel,
+    Grid, Head, HitLocation, ManaLevel, Outcome, Position, QuickContest, Random, RangedStats,
+    Reach, Skill, SuccessRoll,
+};
+
+/// A [`Character`] taking part in a combat encounter.
+#[derive(Debug, Clone, PartialEq, PartialOrd, derive_getters::Getters)]
+pub struct Combatant {
+    character: Character,
+    /// Random roll used to break ties between combatants with the same Basic Speed and DX.
+    tiebreak: usize,
+    /// Maneuver declared on the combatant's most recent turn.
+    manuever: Option<Manuever>,
+    /// Meters moved so far this turn.
+    moved: usize,
+    /// Attacks made so far this turn.
+    attacks: usize,
+    posture: Posture,
+    /// Whether the declared maneuver has already been spent on a change of posture.
+    posture_changed: bool,
+    /// Free actions taken this turn.
+    free_actions: Vec<FreeAction>,
+    /// Held action from a declared Wait.
+    waiting: Option<Wait>,
+    position: Position,
+    /// Index into the grid's directions.
+    facing: usize,
+    /// Sides turned so far this turn.
+    turned: usize,
+    /// Aim carried over from consecutive turns of aiming.
+    aim: Option<Aim>,
+    /// Evaluate carried over from consecutive turns of evaluating.
+    evaluate: Option<Evaluate>,
+    /// Whether the combatant has feinted this turn.
+    has_feinted: bool,
+    /// Pending penalty to defend against a foe who feinted this combatant.
+    feinted_by: Option<Feint>,
+    /// Combatant this one is holding in close combat.
+    grappling: Option<usize>,
+    /// Hold a foe has on this combatant.
+    grappled_by: Option<Grapple>,
+}
+
+impl Combatant {
+    pub fn new(character: Character, random: &mut Random) -> Self {
+        let tiebreak = random.roll();
+        Self {
+            character,
+            tiebreak,
+            manuever: None,
+            moved: 0,
+            attacks: 0,
+            posture: Posture::default(),
+            posture_changed: false,
+            free_actions: Vec::new(),
+            waiting: None,
+            position: Position::default(),
+            facing: 0,
+            turned: 0,
+            aim: None,
+            evaluate: None,
+            has_feinted: false,
+            feinted_by: None,
+            grappling: None,
+            grappled_by: None,
+        }
+    }
+
+    /// Declares the maneuver for this turn, replacing the one from the previous turn, and
+    /// maintains the combatant's spells.  Returns the energy spent on upkeep.
+    pub fn declare(&mut self, manuever: Manuever) -> usize {
+        self.switch_to(manuever);
+        self.upkeep()
+    }
+
+    /// Replaces the current maneuver without starting a new turn.
+    /// Aim and Evaluate bonuses only carry into further turns of the same maneuver or an attack.
+    fn switch_to(&mut self, manuever: Manuever) {
+        tracing::trace!("{} declares {manuever}.", self.name());
+        if !(manuever == Manuever::Aim || manuever.allows_attack()) {
+            self.aim = None;
+        }
+        if !(manuever == Manuever::Evaluate
+            || manuever == Manuever::Feint
+            || manuever.allows_attack())
+        {
+            self.evaluate = None;
+        }
+        self.manuever = Some(manuever);
+        self.moved = 0;
+        self.attacks = 0;
+        self.posture_changed = false;
+        self.has_feinted = false;
+        self.free_actions.clear();
+        self.waiting = None;
+        self.turned = 0;
+    }
+
+    /// Puts the combatant on the map, outside of any maneuver.
+    pub fn place(&mut self, position: Position, facing: usize) {
+        self.position = position;
+        self.facing = facing;
+    }
+
+    /// Moves into the adjacent cell `to`, spending movement from the declared maneuver.
+    pub fn step_to(&mut self, grid: &Grid, to: Position) -> Result<(), ManueverError> {
+        let cost = grid
+            .move_cost(&self.position, self.facing, &to)
+            .ok_or(ManueverError::Blocked(to))?;
+        self.travel(cost)?;
+        self.position = to;
+        Ok(())
+    }
+
+    /// Turns to `facing`.  Any maneuver may turn one side for free; each further side costs one
+    /// point of movement. BS-386
+    pub fn face(&mut self, grid: &Grid, facing: usize) -> Result<(), ManueverError> {
+        let sides = grid.turn_cost(self.facing, facing);
+        let free = 1usize.saturating_sub(self.turned);
+        let cost = sides.saturating_sub(free);
+        if cost > 0 {
+            self.travel(cost)?;
+        }
+        self.turned += sides;
+        self.facing = facing;
+        Ok(())
+    }
+
+    /// Declares a Wait, holding `wait` until its trigger fires or the next turn begins.
+    /// Returns the energy spent on upkeep.
+    pub fn wait(&mut self, wait: Wait) -> usize {
+        let energy = self.declare(Manuever::Wait);
+        tracing::trace!("{} waits for {}.", self.name(), wait.trigger());
+        self.waiting = Some(wait);
+        energy
+    }
+
+    /// Fires a held Wait, declaring its response maneuver.
+    fn respond(&mut self) -> Option<Wait> {
+        let wait = self.waiting.take()?;
+        let free_actions = std::mem::take(&mut self.free_actions);
+        self.switch_to(*wait.response());
+        self.free_actions = free_actions;
+        Some(wait)
+    }
+
+    /// Takes a free action, up to the action's limit per turn.  A Crouch crouches when standing
+    /// and stands when crouching.
+    pub fn free_action(&mut self, action: FreeAction) -> Result<(), ManueverError> {
+        if let Some(limit) = action.limit() {
+            let taken = self.free_actions.iter().filter(|a| **a == action).count();
+            if taken >= limit {
+                return Err(ManueverError::FreeActionLimit(action, limit));
+            }
+        }
+        if action == FreeAction::Crouch {
+            let to = match self.posture {
+                Posture::Crouching => Posture::Standing,
+                _ => Posture::Crouching,
+            };
+            self.change_posture(to)?;
+        }
+        tracing::trace!("{} takes free action {action}.", self.name());
+        self.free_actions.push(action);
+        Ok(())
+    }
+
+    /// Declares an Aim at `target`, adding a second to the aim if the combatant was already
+    /// aiming at that target last turn.  Returns the energy spent on upkeep.
+    pub fn take_aim(&mut self, target: usize, braced: bool) -> usize {
+        let continuing = self.manuever == Some(Manuever::Aim);
+        let energy = self.declare(Manuever::Aim);
+        match self.aim.as_mut() {
+            Some(aim) if continuing && *aim.target() == target && *aim.braced() == braced => {
+                aim.extend()
+            }
+            _ => self.aim = Some(Aim::new(target, 1, braced)),
+        }
+        energy
+    }
+
+    /// Declares an Evaluate of `target`, adding a turn if the combatant was already evaluating
+    /// that target last turn.  Returns the energy spent on upkeep.
+    pub fn evaluate_foe(&mut self, target: usize) -> usize {
+        let continuing = self.manuever == Some(Manuever::Evaluate);
+        let energy = self.declare(Manuever::Evaluate);
+        match self.evaluate.as_mut() {
+            Some(evaluate) if continuing && *evaluate.target() == target => evaluate.extend(),
+            _ => self.evaluate = Some(Evaluate::new(target, 1)),
+        }
+        energy
+    }
+
+    /// When injured while aiming, make a Will roll or lose the Aim. BS-364
+    pub fn injured(&mut self, random: &mut Random) -> Option<SuccessRoll> {
+        self.aim?;
+        let will = self.character.attributes().value(&AttributeType::Willpower) as i64;
+        let roll = random.success_roll(will);
+        if !roll.is_success() {
+            tracing::trace!("{} loses their aim.", self.name());
+            self.aim = None;
+        }
+        Some(roll)
+    }
+
+    /// Takes a hit of `damage` at `location`, applying armor and losing any aim if injured.
+    /// Returns the injury.
+    pub fn hit(
+        &mut self,
+        damage: i64,
+        damage_type: &DamageType,
+        location: &HitLocation,
+        random: &mut Random,
+    ) -> i64 {
+        let injury = self.character.injure(damage, damage_type, location);
+        tracing::trace!("{} takes {injury} injury to the {location}.", self.name());
+        if injury > 0 {
+            self.injured(random);
+        }
+        injury
+    }
+
+    /// Checks `action` against the declared maneuver.
+    pub fn validate(&self, action: &Action) -> Result<(), ManueverError> {
+        let manuever = self.manuever.ok_or(ManueverError::Undeclared)?;
+        manuever.validate(action, self.basic_move(), self.moved)
+    }
+
+    /// Move for the combatant at their current encumbrance and posture.
+    pub fn basic_move(&self) -> usize {
+        self.posture.movement(self.character.current_move())
+    }
+
+    /// Changes posture, if the declared maneuver allows it.  Crouching and rising from a crouch
+    /// are free; kneeling and standing may replace the maneuver's step; anything else takes a
+    /// Change Posture maneuver per level of posture. B-364
+    pub fn change_posture(&mut self, to: Posture) -> Result<(), ManueverError> {
+        let manuever = self.manuever.ok_or(ManueverError::Undeclared)?;
+        let from = self.posture;
+        let step = MoveAllowance::Step.meters(self.basic_move());
+        if from.is_step(&to) && self.moved == 0 && manuever.movement() != MoveAllowance::None {
+            self.moved = step;
+        } else if manuever == Manuever::ChangePosture
+            && !self.posture_changed
+            && from.maneuvers_to(&to) == 1
+        {
+            self.posture_changed = true;
+        } else if !from.is_free(&to) {
+            return Err(ManueverError::Posture { manuever, from, to });
+        }
+        tracing::trace!("{} changes from {from} to {to}.", self.name());
+        self.posture = to;
+        Ok(())
+    }
+
+    /// Moves the combatant `meters` as part of their maneuver.
+    pub fn travel(&mut self, meters: usize) -> Result<(), ManueverError> {
+        if let Some(grapple) = self.grappled_by {
+            return Err(ManueverError::Grappled(*grapple.by()));
+        }
+        self.validate(&Action::Move(meters))?;
+        self.moved += meters;
+        Ok(())
+    }
+
+    /// Modifier to hit for the next attack of the given kind under the declared maneuver, with
+    /// `ranged` the stats of a ranged weapon.  Suppression Fire needs a weapon of RoF 5 or more.
+    /// The second attack of an All-Out Attack (Double) is made with the off hand, at -4 unless
+    /// the character has Ambidexterity. BS-365
+    pub fn attack(
+        &self,
+        kind: AttackKind,
+        ranged: Option<&RangedStats>,
+    ) -> Result<i64, ManueverError> {
+        self.validate(&Action::Attack(kind))?;
+        let manuever = self.manuever.ok_or(ManueverError::Undeclared)?;
+        if self.attacks >= manuever.attacks() {
+            return Err(ManueverError::NoAttacksLeft(manuever, manuever.attacks()));
+        }
+        if let Manuever::AllOutAttack(option) = manuever {
+            let rof = ranged.map(|r| *r.rof()).unwrap_or(1);
+            if rof < option.min_rof() {
+                return Err(ManueverError::RateOfFire {
+                    manuever,
+                    required: option.min_rof(),
+                    rof,
+                });
+            }
+        }
+        let double = Manuever::AllOutAttack(AllOutAttack::Melee(AllOutMeleeAttack::Double));
+        let off_hand = if manuever == double && self.attacks > 0 && !self.is_ambidextrous() {
+            -4
+        } else {
+            0
+        };
+        let bulk = ranged.map(|r| *r.bulk()).unwrap_or_default();
+        Ok(manuever.attack_modifier(&kind, bulk) + off_hand)
+    }
+
+    /// Damage for an attack with base damage `damage` under the declared maneuver, including the
+    /// All-Out Attack (Strong) bonus.
+    pub fn damage(&self, damage: &DieLevel) -> DieLevel {
+        match self.manuever {
+            Some(manuever) => manuever.damage(damage),
+            None => *damage,
+        }
+    }
+
+    fn is_ambidextrous(&self) -> bool {
+        self.character
+            .special_features()
+            .advantages()
+            .contains(&Advantage::Ambidexterity)
+    }
+
+    /// Rolls an attack of the given kind against `target` at effective `skill`, with `ranged`
+    /// the stats of a ranged weapon.
+    /// Any Aim (ranged, with the weapon's Accuracy) or Evaluate (melee) bonus accumulated against
+    /// the target is added and used up.
+    pub fn attack_roll(
+        &mut self,
+        kind: AttackKind,
+        skill: i64,
+        target: usize,
+        ranged: Option<&RangedStats>,
+        random: &mut Random,
+    ) -> Result<SuccessRoll, ManueverError> {
+        let manuever = self.manuever.ok_or(ManueverError::Undeclared)?;
+        let mut effective =
+            skill + self.attack(kind, ranged)? + self.posture.attack() + self.grapple_penalty();
+        if let Some(aim) = self.aim.take() {
+            if kind == AttackKind::Ranged && *aim.target() == target {
+                let acc = ranged.map(|r| *r.acc()).unwrap_or_default();
+                effective += aim.bonus(acc);
+            }
+        }
+        if let Some(evaluate) = self.evaluate.take() {
+            if kind == AttackKind::Melee && *evaluate.target() == target {
+                effective += evaluate.bonus();
+            }
+        }
+        if let Some(cap) = manuever.skill_cap(&kind) {
+            effective = effective.min(cap);
+        }
+        self.attacks += 1;
+        Ok(random.success_roll(effective))
+    }
+
+    /// Level to roll for a Feint made with `skill`: the higher of that skill, an unarmed combat
+    /// skill, Cloak, Shield, or DX. BS-365
+    pub fn feint_level(&self, skill: &Skill) -> i64 {
+        let dx = self.character.attributes().value(&AttributeType::Dexterity) as i64;
+        [
+            *skill,
+            Skill::Boxing,
+            Skill::Brawling,
+            Skill::Karate,
+            Skill::Cloak,
+            Skill::Shield,
+        ]
+        .iter()
+        .filter_map(|s| self.character.effective_skill(s))
+        .fold(dx, i64::max)
+    }
+
+    /// Checks that the combatant may use `defense` and returns the maneuver and posture modifier
+    /// to it, to add to the defense in the character's profile, which already includes trait
+    /// modifiers such as Combat Reflexes.
+    /// Defenses depend on the maneuver from the combatant's last turn, so a combatant who has not
+    /// yet acted may defend freely.
+    /// Any active defense spoils an Aim. BS-364
+    pub fn defend(&mut self, defense: ActiveDefense) -> Result<i64, ManueverError> {
+        if self.grappled_by.is_some_and(|g| *g.pinned()) {
+            return Err(ManueverError::Pinned);
+        }
+        let grappled = match defense {
+            ActiveDefense::Dodge => 0,
+            ActiveDefense::Parry | ActiveDefense::Block => self.grapple_penalty(),
+        };
+        let bonus = grappled
+            + match self.manuever {
+                Some(manuever) => {
+                    manuever.validate(&Action::Defend(defense), 0, 0)?;
+                    manuever.defense_bonus(&defense)
+                }
+                None => 0,
+            }
+            + self.posture.defense();
+        self.aim = None;
+        Ok(bonus)
+    }
+
+    /// Tries `defense` against an attack that `previous` has already failed to stop.  Only an
+    /// All-Out Defense (Double Defense) allows this, and the two defenses must differ. BS-366
+    pub fn defend_again(
+        &mut self,
+        previous: ActiveDefense,
+        defense: ActiveDefense,
+    ) -> Result<i64, ManueverError> {
+        let manuever = self.manuever.ok_or(ManueverError::Undeclared)?;
+        if manuever.defenses_per_attack() < 2 {
+            return Err(ManueverError::NoSecondDefense(manuever));
+        }
+        if previous == defense {
+            return Err(ManueverError::RepeatedDefense(defense));
+        }
+        self.defend(defense)
+    }
+
+    /// Maintains every spell the combatant has on, each a free action, at the start of their
+    /// turn.  Returns the energy spent.
+    pub fn upkeep(&mut self) -> usize {
+        for _ in 0..self.character.maintained().len() {
+            // Maintaining a spell has no limit per turn.
+            let _ = self.free_action(FreeAction::MaintainSpell);
+        }
+        self.character.maintain()
+    }
+
+    /// Casts a spell, which takes the Concentrate maneuver. BS-366
+    pub fn cast(
+        &mut self,
+        casting: &Casting,
+        mana: &ManaLevel,
+        random: &mut Random,
+    ) -> Result<CastResult, CastingError> {
+        if self.manuever != Some(Manuever::Concentrate) {
+            return Err(CastingError::NotConcentrating);
+        }
+        self.character.cast(casting, mana, random)
+    }
+
+    /// Casts a spell as a song or tune, which also takes the Concentrate maneuver.
+    pub fn perform(
+        &mut self,
+        casting: &Casting,
+        skill: &Skill,
+        mana: &ManaLevel,
+        random: &mut Random,
+    ) -> Result<CastResult, CastingError> {
+        if self.manuever != Some(Manuever::Concentrate) {
+            return Err(CastingError::NotConcentrating);
+        }
+        self.character.perform(casting, skill, mana, random)
+    }
+
+    /// Penalty to DX and DX-based skills from being grappled.
+    pub fn grapple_penalty(&self) -> i64 {
+        match self.grappled_by {
+            Some(_) => Grapple::DX_PENALTY,
+            None => 0,
+        }
+    }
+
+    /// Best of ST, DX and the grappling skills, used to grapple, take down and break free.
+    /// BS-370
+    pub fn grapple_level(&self) -> i64 {
+        let attributes = self.character.attributes();
+        let st = attributes.value(&AttributeType::Strength) as i64;
+        let dx = attributes.value(&AttributeType::Dexterity) as i64;
+        [Skill::Judo, Skill::SumoWrestling, Skill::Wrestling]
+            .iter()
+            .filter_map(|skill| self.character.effective_skill(skill))
+            .chain([dx])
+            .map(|level| level + self.grapple_penalty())
+            .chain([st])
+            .max()
+            .unwrap_or(st)
+    }
+
+    fn strength(&self) -> i64 {
+        self.character.attributes().value(&AttributeType::Strength) as i64
+    }
+
+    /// Lets go of any foe being held.
+    pub fn release(&mut self) -> Option<usize> {
+        self.grappling.take()
+    }
+
+    pub fn name(&self) -> &str {
+        self.character.name()
+    }
+
+    /// Turn order is by highest Basic Speed, ties go to highest DX. BS-363
+    /// Remaining ties are settled by the random tiebreak roll.
+    fn initiative(&self, other: &Self) -> Ordering {
+        let speed = self.character.stats().basic_speed();
+        let other_speed = other.character.stats().basic_speed();
+        let dx = self.character.attributes().value(&AttributeType::Dexterity);
+        let other_dx = other
+            .character
+            .attributes()
+            .value(&AttributeType::Dexterity);
+        other_speed
+            .partial_cmp(speed)
+            .unwrap_or(Ordering::Equal)
+            .then(other_dx.cmp(&dx))
+            .then(other.tiebreak.cmp(&self.tiebreak))
+    }
+}
+
+/// A combat encounter, run second by second in turn order. BS-362
+#[derive(Debug, Clone, PartialEq, derive_getters::Getters)]
+pub struct Combat {
+    combatants: Vec<Combatant>,
+    /// Indices into `combatants`, sorted by turn order.
+    order: Vec<usize>,
+    /// Position in `order` of the combatant whose turn it is.
+    turn: usize,
+    /// Elapsed seconds of combat, starting from one.
+    second: usize,
+    /// Map the combatants fight on.
+    grid: Grid,
+    /// Waiting combatant acting out of turn, ahead of the combatant at `turn`.
+    interrupt: Option<usize>,
+}
+
+impl Combat {
+    pub fn new(characters: Vec<Character>, random: &mut Random) -> Self {
+        let combatants = characters
+            .into_iter()
+            .map(|c| Combatant::new(c, random))
+            .collect::<Vec<Combatant>>();
+        let mut order = (0..combatants.len()).collect::<Vec<usize>>();
+        order.sort_by(|a, b| combatants[*a].initiative(&combatants[*b]));
+        Self {
+            combatants,
+            order,
+            turn: 0,
+            second: 1,
+            grid: Grid::default(),
+            interrupt: None,
+        }
+    }
+
+    /// Index of the combatant whose turn it is, or `None` if nobody is fighting.  A combatant
+    /// whose Wait has fired acts before the combatant whose turn was interrupted.
+    pub fn current_id(&self) -> Option<usize> {
+        self.interrupt
+            .or_else(|| self.order.get(self.turn).copied())
+    }
+
+    /// The combatant whose turn it is.
+    pub fn current(&self) -> Option<&Combatant> {
+        self.current_id().map(|id| &self.combatants[id])
+    }
+
+    pub fn combatant(&self, id: usize) -> Option<&Combatant> {
+        self.combatants.get(id)
+    }
+
+    pub fn combatant_mut(&mut self, id: usize) -> Option<&mut Combatant> {
+        self.combatants.get_mut(id)
+    }
+
+    pub fn grid_mut(&mut self) -> &mut Grid {
+        &mut self.grid
+    }
+
+    /// Moves the combatant whose turn it is into the adjacent cell `to`.
+    pub fn step_to(&mut self, to: Position) -> Result<(), ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        self.combatants[id].step_to(&self.grid, to)
+    }
+
+    /// Turns the combatant whose turn it is to `facing`.
+    pub fn face(&mut self, facing: usize) -> Result<(), ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        self.combatants[id].face(&self.grid, facing)
+    }
+
+    /// Whether `attacker` can strike `target` with `reach` from where they stand.
+    pub fn in_reach(&self, attacker: usize, target: usize, reach: &Reach) -> bool {
+        match (self.combatant(attacker), self.combatant(target)) {
+            (Some(a), Some(t)) => self
+                .grid
+                .in_reach(&a.position, a.facing, &t.position, reach),
+            _ => false,
+        }
+    }
+
+    /// Whether `attacker` can close to strike `target` with `reach` in a Move and Attack, moving
+    /// no further than their Move.
+    pub fn can_move_and_attack(&self, attacker: usize, target: usize, reach: &Reach) -> bool {
+        match (self.combatant(attacker), self.combatant(target)) {
+            (Some(a), Some(t)) => {
+                let points = Manuever::MoveAndAttack.movement().meters(a.basic_move());
+                self.grid.can_close(&a.position, &t.position, reach, points)
+            }
+            _ => false,
+        }
+    }
+
+    /// Declares a maneuver for the combatant whose turn it is.
+    pub fn declare(&mut self, manuever: Manuever) -> Option<&Combatant> {
+        let id = self.current_id()?;
+        self.combatants[id].declare(manuever);
+        Some(&self.combatants[id])
+    }
+
+    /// Declares a Wait for the combatant whose turn it is.
+    pub fn wait(&mut self, wait: Wait) -> Option<&Combatant> {
+        let id = self.current_id()?;
+        self.combatants[id].wait(wait);
+        Some(&self.combatants[id])
+    }
+
+    /// Combatants in the order they act each second.
+    pub fn turn_order(&self) -> Vec<&Combatant> {
+        self.order.iter().map(|id| &self.combatants[*id]).collect()
+    }
+
+    /// Signals that `event` has happened.  The first combatant in turn order waiting on it
+    /// interrupts the current turn, taking their Wait response now.
+    pub fn fire(&mut self, event: &Trigger) -> Option<Wait> {
+        let acting = self.current_id();
+        let id = self.order.iter().copied().find(|id| {
+            Some(*id) != acting
+                && self.combatants[*id]
+                    .waiting
+                    .as_ref()
+                    .is_some_and(|w| w.trigger() == event)
+        })?;
+        let wait = self.combatants[id].respond()?;
+        tracing::trace!("{} interrupts on {event}.", self.combatants[id].name());
+        self.interrupt = Some(id);
+        Some(wait)
+    }
+
+    /// The combatant whose turn it is feints `target` using `skill`, resisted by the target's
+    /// `target_skill`.  Returns the penalty the target takes to defend against the feinter's
+    /// next attack.  An Evaluate of the target adds to the feinter's roll and is used up.
+    /// BS-365
+    pub fn feint(
+        &mut self,
+        target: usize,
+        skill: &Skill,
+        target_skill: &Skill,
+        random: &mut Random,
+    ) -> Result<i64, ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        let manuever = self.combatants[id]
+            .manuever
+            .ok_or(ManueverError::Undeclared)?;
+        if !manuever.allows_feint() || self.combatants[id].has_feinted {
+            return Err(ManueverError::NoFeint(manuever));
+        }
+        if target >= self.combatants.len() {
+            return Err(ManueverError::NoTarget(target));
+        }
+        let evaluate = self.combatants[id]
+            .evaluate
+            .take()
+            .filter(|e| *e.target() == target)
+            .map(|e| e.bonus())
+            .unwrap_or_default();
+        let foe = &self.combatants[target];
+        let level = self.combatants[id].feint_level(skill) + evaluate;
+        let contest = random.quick_contest(level, foe.feint_level(target_skill));
+        let penalty = Feint::penalty_from(&contest);
+        tracing::trace!(
+            "{} feints {}: -{penalty} to defend.",
+            self.combatants[id].name(),
+            foe.name()
+        );
+        self.combatants[id].has_feinted = true;
+        if penalty > 0 {
+            self.combatants[target].feinted_by = Some(Feint::new(id, penalty, self.second));
+        }
+        Ok(penalty)
+    }
+
+    /// The combatant whose turn it is attacks `target` at effective `skill`, with `ranged` the
+    /// stats of a ranged weapon.  A pending Feint by the attacker on the target sets the penalty
+    /// to the target's defense, and is used up unless an All-Out Attack (Double) has its second
+    /// attack still to come.
+    pub fn attack(
+        &mut self,
+        target: usize,
+        kind: AttackKind,
+        skill: i64,
+        ranged: Option<&RangedStats>,
+        random: &mut Random,
+    ) -> Result<AttackRoll, ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        let attacker = &mut self.combatants[id];
+        let roll = attacker.attack_roll(kind, skill, target, ranged, random)?;
+        let attacks_left =
+            attacker.manuever.map(|m| m.attacks()).unwrap_or_default() > attacker.attacks;
+        let second = self.second;
+        let mut defense_penalty = 0;
+        if let Some(foe) = self.combatants.get_mut(target) {
+            if let Some(feint) = foe.feinted_by {
+                if *feint.by() == id && feint.is_current(second) {
+                    defense_penalty = *feint.penalty();
+                }
+                if !(attacks_left && *feint.by() == id) {
+                    foe.feinted_by = None;
+                }
+            }
+        }
+        Ok(AttackRoll::new(roll, defense_penalty))
+    }
+
+    /// The combatant whose turn it is grabs at `target`, a melee attack at the better of DX and
+    /// the grappling skills.  A grab that hits takes hold unless the target Dodges, where their
+    /// last maneuver allows it.  Returns the grab roll. BS-370
+    pub fn grapple(
+        &mut self,
+        target: usize,
+        random: &mut Random,
+    ) -> Result<SuccessRoll, ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        if target == id {
+            return Err(ManueverError::NotGrappling(target));
+        }
+        if target >= self.combatants.len() {
+            return Err(ManueverError::NoTarget(target));
+        }
+        let attacker = &mut self.combatants[id];
+        let dx = attacker
+            .character
+            .attributes()
+            .value(&AttributeType::Dexterity) as i64;
+        let skill = [Skill::Judo, Skill::SumoWrestling, Skill::Wrestling]
+            .iter()
+            .filter_map(|s| attacker.character.effective_skill(s))
+            .fold(dx, i64::max);
+        let roll = attacker.attack_roll(AttackKind::Melee, skill, target, None, random)?;
+        if !roll.is_success() {
+            return Ok(roll);
+        }
+        let foe = &mut self.combatants[target];
+        let dodged = roll.outcome() != &Outcome::CriticalSuccess
+            && foe.defend(ActiveDefense::Dodge).is_ok_and(|bonus| {
+                let dodge = *foe.character.profile().dodge() + bonus;
+                random.success_roll(dodge).is_success()
+            });
+        if !dodged {
+            foe.grappled_by = Some(Grapple::new(id, false));
+            tracing::trace!("{} is grappled.", foe.name());
+            self.combatants[id].grappling = Some(target);
+        }
+        Ok(roll)
+    }
+
+    /// Checks that the combatant whose turn it is can attack while holding `target`.
+    fn close_attack(&mut self, target: usize) -> Result<usize, ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        let attacker = &self.combatants[id];
+        let held = attacker.grappling == Some(target)
+            || attacker.grappled_by.is_some_and(|g| *g.by() == target);
+        if !held || target >= self.combatants.len() {
+            return Err(ManueverError::NotGrappling(target));
+        }
+        attacker.attack(AttackKind::Melee, None)?;
+        self.combatants[id].attacks += 1;
+        Ok(id)
+    }
+
+    /// The combatant whose turn it is tries to throw `target` to the ground, in a Quick Contest
+    /// of each side's best grappling level.  Either fighter in a grapple may attempt it. BS-370
+    pub fn takedown(
+        &mut self,
+        target: usize,
+        random: &mut Random,
+    ) -> Result<QuickContest, ManueverError> {
+        let id = self.close_attack(target)?;
+        let contest = random.quick_contest(
+            self.combatants[id].grapple_level(),
+            self.combatants[target].grapple_level(),
+        );
+        if contest.first_wins() == Some(true) {
+            let foe = &mut self.combatants[target];
+            foe.posture = Posture::LyingProne;
+            tracing::trace!("{} is taken down.", foe.name());
+        }
+        Ok(contest)
+    }
+
+    /// The combatant whose turn it is tries to pin `target`, already held and on the ground, in a
+    /// Regular Contest of ST. BS-370
+    pub fn pin(
+        &mut self,
+        target: usize,
+        random: &mut Random,
+    ) -> Result<QuickContest, ManueverError> {
+        let id = self.close_attack(target)?;
+        if self.combatants[id].grappling != Some(target) {
+            return Err(ManueverError::NotGrappling(target));
+        }
+        let foe = &self.combatants[target];
+        if !matches!(foe.posture, Posture::LyingProne | Posture::LyingFaceDown) {
+            return Err(ManueverError::NotDown(target));
+        }
+        let contest = random.regular_contest(
+            self.combatants[id].strength(),
+            self.combatants[target].strength(),
+        );
+        if contest.first_wins() == Some(true) {
+            let foe = &mut self.combatants[target];
+            if let Some(grapple) = foe.grappled_by.as_mut() {
+                grapple.pin();
+            }
+            tracing::trace!("{} is pinned.", foe.name());
+        }
+        Ok(contest)
+    }
+
+    /// The combatant whose turn it is chokes `target`, in a Quick Contest of ST against the
+    /// higher of the victim's ST and HT.  The victim takes crushing damage to the neck equal to
+    /// the margin of victory.  Returns the injury. BS-370
+    pub fn choke(&mut self, target: usize, random: &mut Random) -> Result<i64, ManueverError> {
+        let id = self.close_attack(target)?;
+        if self.combatants[id].grappling != Some(target) {
+            return Err(ManueverError::NotGrappling(target));
+        }
+        let foe = &self.combatants[target];
+        let ht = foe.character.attributes().value(&AttributeType::Health) as i64;
+        let contest = random.quick_contest(self.combatants[id].strength(), foe.strength().max(ht));
+        if contest.first_wins() != Some(true) {
+            return Ok(0);
+        }
+        let neck = HitLocation::Head(Head::Neck);
+        Ok(self.combatants[target].hit(contest.margin(), &DamageType::Crushing, &neck, random))
+    }
+
+    /// The combatant whose turn it is tries to break a hold, in a Quick Contest of their best
+    /// grappling level against the holder's.  Pinned combatants are at -5 more. BS-371
+    pub fn break_free(&mut self, random: &mut Random) -> Result<QuickContest, ManueverError> {
+        let id = self.current_id().ok_or(ManueverError::Undeclared)?;
+        let grapple = self.combatants[id]
+            .grappled_by
+            .ok_or(ManueverError::NotGrappled)?;
+        let pinned = if *grapple.pinned() { -5 } else { 0 };
+        let contest = random.quick_contest(
+            self.combatants[id].grapple_level() + pinned,
+            self.combatants[*grapple.by()].grapple_level(),
+        );
+        if contest.first_wins() == Some(true) {
+            self.combatants[id].grappled_by = None;
+            self.combatants[*grapple.by()].grappling = None;
+            tracing::trace!("{} breaks free.", self.combatants[id].name());
+        }
+        Ok(contest)
+    }
+
+    /// The combatant whose turn it is lets go of the foe they hold.
+    pub fn release(&mut self) -> Option<usize> {
+        let id = self.current_id()?;
+        let target = self.combatants[id].release()?;
+        if let Some(foe) = self.combatants.get_mut(target) {
+            foe.grappled_by = None;
+        }
+        Some(target)
+    }
+
+    /// Ends the current turn and passes it to the next combatant in order.  When every combatant
+    /// has acted, the next second of combat begins.  Ending an interrupting combatant's action
+    /// returns to the interrupted turn.
+    pub fn advance(&mut self) -> Option<&Combatant> {
+        if self.order.is_empty() {
+            return None;
+        }
+        if self.interrupt.take().is_some() {
+            return self.current();
+        }
+        self.turn += 1;
+        if self.turn >= self.order.len() {
+            self.turn = 0;
+            self.second += 1;
+            tracing::trace!("Second {} begins.", self.second);
+        }
+        self.current()
+    }
+}
+
+/// An attack roll and the penalty the target takes to defend against it.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct AttackRoll {
+    roll: SuccessRoll,
+    defense_penalty: i64,
+}
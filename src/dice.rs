@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use rand::distr::Distribution;
 
 #[derive(
@@ -43,6 +45,7 @@ impl Dice {
     Hash,
     serde::Serialize,
     serde::Deserialize,
+    derive_getters::Getters,
     derive_new::new,
 )]
 pub struct DieLevel {
@@ -50,23 +53,106 @@ pub struct DieLevel {
     pips: i64,
 }
 
+impl DieLevel {
+    /// Adds `pips` to the damage, keeping the number of dice.
+    pub fn with_pips(&self, pips: i64) -> Self {
+        Self::new(self.dice, self.pips + pips)
+    }
+}
+
+impl std::fmt::Display for DieLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.pips {
+            0 => write!(f, "{}d", self.dice),
+            pips => write!(f, "{}d{:+}", self.dice, pips),
+        }
+    }
+}
+
+/// Most success rolls kept in a `Random`'s log; older rolls are dropped first.
+pub const LOG_LIMIT: usize = 100;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Random {
     range: rand::rngs::StdRng,
     die: rand::distr::Uniform<usize>,
+    /// The last `LOG_LIMIT` success rolls made, oldest first.
+    log: VecDeque<SuccessRoll>,
 }
 
 impl Random {
     pub fn from_seed(seed: u64) -> Result<Self, rand::distr::uniform::Error> {
         let range = rand::SeedableRng::seed_from_u64(seed);
         let die = rand::distr::Uniform::new(1, 7)?;
-        Ok(Self { range, die })
+        Ok(Self {
+            range,
+            die,
+            log: VecDeque::new(),
+        })
     }
 
     pub fn roll_die(&mut self) -> usize {
         self.die.sample(&mut self.range)
     }
 
+    /// Rolls a Quick Contest between effective levels `first` and `second`.
+    pub fn quick_contest(&mut self, first: i64, second: i64) -> QuickContest {
+        let first = self.success_roll(first);
+        let second = self.success_roll(second);
+        QuickContest::new(first, second)
+    }
+
+    /// Rolls a Regular Contest between effective levels `first` and `second`: both roll until
+    /// one succeeds and the other fails.  The deciding round is returned. BS-349
+    pub fn regular_contest(&mut self, first: i64, second: i64) -> QuickContest {
+        loop {
+            let contest = self.quick_contest(first, second);
+            if contest.first().is_success() != contest.second().is_success() {
+                return contest;
+            }
+        }
+    }
+
+    /// Rolls 3d6 against `target`, recording it in the log.
+    pub fn success_roll(&mut self, target: i64) -> SuccessRoll {
+        let roll = SuccessRoll::from_roll(target, self.roll());
+        if self.log.len() == LOG_LIMIT {
+            self.log.pop_front();
+        }
+        self.log.push_back(roll);
+        roll
+    }
+
+    /// The last `LOG_LIMIT` success rolls made, oldest first.
+    pub fn log(&self) -> &VecDeque<SuccessRoll> {
+        &self.log
+    }
+
+    /// Forgets every logged roll, such as at the end of a turn.
+    pub fn clear_log(&mut self) {
+        self.log.clear();
+    }
+
+    /// Rolls the logged roll at `index` `times` more, keeping the lowest of all and replacing it
+    /// in the log.  `None` if there is no such roll.  Indexes shift down as old rolls are dropped.
+    pub fn reroll(&mut self, index: usize, times: usize) -> Option<SuccessRoll> {
+        let original = *self.log.get(index)?;
+        let best = (0..times)
+            .map(|_| SuccessRoll::from_roll(*original.target(), self.roll()))
+            .fold(
+                original,
+                |best, roll| {
+                    if roll.roll < best.roll {
+                        roll
+                    } else {
+                        best
+                    }
+                },
+            );
+        self.log[index] = best;
+        Some(best)
+    }
+
     pub fn roll(&mut self) -> usize {
         // let mut total = 0;
         // for _ in 0..3 {
@@ -83,6 +169,128 @@ impl Default for Random {
         let range = <rand::rngs::StdRng as rand::SeedableRng>::from_rng(&mut rng);
         // Quick and dirty method, may panic on ...?
         let die = rand::distr::Uniform::new(1, 7).unwrap();
-        Self { range, die }
+        Self {
+            range,
+            die,
+            log: VecDeque::new(),
+        }
+    }
+}
+
+/// Result of a success roll: 3d6 against an effective skill or attribute. BS-343
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct SuccessRoll {
+    /// Effective skill or attribute rolled against.
+    target: i64,
+    roll: usize,
+    /// Amount by which the roll succeeded, negative on a failure.
+    margin: i64,
+    outcome: Outcome,
+}
+
+impl SuccessRoll {
+    pub fn from_roll(target: i64, roll: usize) -> Self {
+        let margin = target - roll as i64;
+        // Critical success on 3 or 4, 5 at skill 15+, 6 at skill 16+.
+        // Critical failure on 18, 17 at skill 15 or less, or a failure by 10 or more.
+        // 17 or 18 always fails. BS-348
+        let outcome = match roll {
+            0..=4 => Outcome::CriticalSuccess,
+            5 if target >= 15 => Outcome::CriticalSuccess,
+            6 if target >= 16 => Outcome::CriticalSuccess,
+            18.. => Outcome::CriticalFailure,
+            17 if target <= 15 => Outcome::CriticalFailure,
+            17 => Outcome::Failure,
+            _ if margin <= -10 => Outcome::CriticalFailure,
+            _ if margin >= 0 => Outcome::Success,
+            _ => Outcome::Failure,
+        };
+        Self {
+            target,
+            roll,
+            margin,
+            outcome,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.outcome.is_success()
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum Outcome {
+    CriticalSuccess,
+    Success,
+    Failure,
+    CriticalFailure,
+}
+
+impl Outcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::CriticalSuccess | Self::Success)
+    }
+}
+
+/// Both sides of a Quick Contest, each rolling once against their own level. BS-348
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct QuickContest {
+    first: SuccessRoll,
+    second: SuccessRoll,
+}
+
+impl QuickContest {
+    /// The side that won: `Some(true)` for the first, `Some(false)` for the second, `None` on a
+    /// tie.  A success beats a failure; otherwise the larger margin wins.
+    pub fn first_wins(&self) -> Option<bool> {
+        let first = (self.first.is_success(), self.first.margin);
+        let second = (self.second.is_success(), self.second.margin);
+        match first.cmp(&second) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Less => Some(false),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Margin of victory of the first side over the second, negative if the second won.
+    pub fn margin(&self) -> i64 {
+        self.first.margin - self.second.margin
     }
 }
@@ -0,0 +1,188 @@
+use crate::{Disadvantage, Random, SuccessRoll};
+
+/// Highest level a Fright Check is rolled against; a roll of 14 or more always fails. BS-360
+pub const FRIGHT_CHECK_MAX: i64 = 13;
+
+/// Result of a failed Fright Check, by 3d6 plus the margin of failure.  Stuns and faints are
+/// left to the GM to run. BS-360
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum FrightEffect {
+    /// Stunned for one second, then recover automatically.
+    Startled,
+    /// Stunned for one second, then roll unmodified Will each second to recover.
+    Stunned,
+    /// Stunned for one second, then roll modified Will each second to recover.
+    Shaken,
+    /// Stunned for 1d seconds, then roll modified Will each second.
+    Dazed,
+    /// Stunned for 2d seconds, then roll modified Will each second.
+    Reeling,
+    /// Retching for 25 - HT seconds.
+    Retching,
+    /// A new mental quirk.
+    Quirk,
+    /// 1d FP lost and 1d seconds stunned.
+    Drained,
+    /// 2d FP lost and 2d seconds stunned.
+    Exhausted,
+    /// Stunned for 1d seconds and a new mental quirk.
+    StunnedQuirk,
+    /// Faint for 1d minutes, rolling HT each minute to recover.
+    Faint,
+    /// Faint as above, and 1 HP of injury on a failed HT roll.
+    FaintInjured,
+    /// Faint for 2d minutes, with 2 HP of injury.
+    SevereFaint,
+    /// Faint for 4d minutes, with 1d FP lost.
+    Shock,
+    /// Panic for 1d minutes, rolling modified Will each minute to snap out of it.
+    Panic,
+    /// A new -10-point Delusion.
+    Delusion,
+    /// A new -10-point Phobia or other mental disadvantage.
+    Phobia,
+    /// Physical disadvantages worth this many points, set by the GM.
+    #[display("Physical({_0})")]
+    Physical(usize),
+    /// A related mental disadvantage grows worse, or a new -10-point Phobia.
+    WorsePhobia,
+    /// Faint as above, and a new -10-point Delusion.
+    FaintDelusion,
+    /// Faint as above, and a new -10-point mental disadvantage.
+    FaintPhobia,
+    /// Unconscious, rolling HT every 30 minutes to recover, then -2 to all rolls for 6 hours.
+    LightComa,
+    /// Unconscious for 1d hours at a time until a HT roll succeeds.
+    Coma,
+    /// Catatonic for 1d days at a time until a HT roll succeeds.
+    Catatonia,
+    /// A fit lasting 1d minutes, with 1d FP lost and 1d injury on a failed HT roll.
+    Seizure,
+    /// A mild heart attack or stroke, with 2d injury.
+    Stricken,
+    /// Total panic for 1d minutes, running amok.
+    TotalPanic,
+    /// A new -15-point Delusion.
+    MajorDelusion,
+    /// A new -15-point Phobia or other mental disadvantage.
+    MajorPhobia,
+    /// Coma, and a new -10-point Delusion.
+    ComaDelusion,
+    /// Coma, and a new -10-point Phobia or other mental disadvantage.
+    ComaPhobia,
+    /// As above, and 1 point of IQ lost permanently.
+    ComaWithLoss,
+}
+
+impl FrightEffect {
+    pub fn from_roll(roll: usize) -> Self {
+        match roll {
+            0..=5 => Self::Startled,
+            6..=7 => Self::Stunned,
+            8..=9 => Self::Shaken,
+            10 => Self::Dazed,
+            11 => Self::Reeling,
+            12 => Self::Retching,
+            13 => Self::Quirk,
+            14 => Self::Drained,
+            15 => Self::Exhausted,
+            16 => Self::StunnedQuirk,
+            17 => Self::Faint,
+            18 => Self::FaintInjured,
+            19 => Self::SevereFaint,
+            20 => Self::Shock,
+            21 => Self::Panic,
+            22 => Self::Delusion,
+            23 => Self::Phobia,
+            24 => Self::Physical(15),
+            25 => Self::WorsePhobia,
+            26 => Self::FaintDelusion,
+            27 => Self::FaintPhobia,
+            28 => Self::LightComa,
+            29 => Self::Coma,
+            30 => Self::Catatonia,
+            31 => Self::Seizure,
+            32 => Self::Stricken,
+            33 => Self::TotalPanic,
+            34 => Self::MajorDelusion,
+            35 => Self::MajorPhobia,
+            36 => Self::Physical(20),
+            37 => Self::Physical(30),
+            38 => Self::ComaDelusion,
+            39 => Self::ComaPhobia,
+            _ => Self::ComaWithLoss,
+        }
+    }
+
+    /// Mental disadvantage gained.  Where the table allows any mental disadvantage, a Phobia is
+    /// taken.
+    pub fn disadvantage(&self) -> Option<Disadvantage> {
+        match self {
+            Self::Quirk | Self::StunnedQuirk => Some(Disadvantage::Quirk),
+            Self::Delusion | Self::FaintDelusion | Self::ComaDelusion => {
+                Some(Disadvantage::Delusion(10))
+            }
+            Self::Phobia
+            | Self::WorsePhobia
+            | Self::FaintPhobia
+            | Self::ComaPhobia
+            | Self::ComaWithLoss => Some(Disadvantage::Phobia(10)),
+            Self::MajorDelusion => Some(Disadvantage::Delusion(15)),
+            Self::MajorPhobia => Some(Disadvantage::Phobia(15)),
+            _ => None,
+        }
+    }
+
+    /// FP lost.
+    pub fn fatigue(&self, random: &mut Random) -> usize {
+        match self {
+            Self::Drained | Self::Shock | Self::Seizure => random.roll_die(),
+            Self::Exhausted => random.roll_die() + random.roll_die(),
+            _ => 0,
+        }
+    }
+
+    /// HP of injury taken by a victim with `ht`.
+    pub fn injury(&self, ht: i64, random: &mut Random) -> usize {
+        match self {
+            Self::FaintInjured if !random.success_roll(ht).is_success() => 1,
+            Self::SevereFaint => 2,
+            Self::Seizure if !random.success_roll(ht).is_success() => random.roll_die(),
+            Self::Stricken => random.roll_die() + random.roll_die(),
+            _ => 0,
+        }
+    }
+}
+
+/// A Will roll against fear, and what a failure did. BS-360
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct FrightCheck {
+    roll: SuccessRoll,
+    /// `None` on a success.
+    effect: Option<FrightEffect>,
+}
@@ -0,0 +1,281 @@
+use std::collections::{BTreeMap, BinaryHeap};
+
+/// Shape of the cells on a tactical combat map.  Each cell is one meter across. BS-384
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum GridKind {
+    /// Hexes, addressed by axial coordinates.
+    #[default]
+    Hex,
+    Square,
+}
+
+impl GridKind {
+    /// Offsets to each adjacent cell, clockwise from straight ahead along the x axis.  A facing
+    /// is an index into this list.
+    pub fn directions(&self) -> &'static [(i64, i64)] {
+        match self {
+            Self::Hex => &[(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)],
+            Self::Square => &[
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+
+    /// Distance in cells between two positions.  Diagonal squares count as one.
+    pub fn distance(&self, a: &Position, b: &Position) -> usize {
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+        let cells = match self {
+            Self::Hex => (dx.abs() + dy.abs() + (dx + dy).abs()) / 2,
+            Self::Square => dx.abs().max(dy.abs()),
+        };
+        cells as usize
+    }
+
+    /// Number of sides turned to go from facing `from` to facing `to`.
+    pub fn turn(&self, from: usize, to: usize) -> usize {
+        let sides = self.directions().len();
+        let diff = (to + sides - from % sides) % sides;
+        diff.min(sides - diff)
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_more::Display,
+    derive_new::new,
+)]
+#[display("({x}, {y})")]
+pub struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn offset(&self, (dx, dy): (i64, i64)) -> Self {
+        Self::new(self.x + dx, self.y + dy)
+    }
+}
+
+/// Ground underfoot, which multiplies the movement cost of entering a cell. BS-387
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Terrain {
+    #[default]
+    Normal,
+    /// Mud, shallow water, dense undergrowth.
+    Bad,
+    /// Deep snow, rubble, waist-deep water.
+    VeryBad,
+    /// Walls and other obstacles.
+    Impassable,
+}
+
+impl Terrain {
+    pub fn cost(&self) -> Option<usize> {
+        match self {
+            Self::Normal => Some(1),
+            Self::Bad => Some(2),
+            Self::VeryBad => Some(4),
+            Self::Impassable => None,
+        }
+    }
+}
+
+/// Distance at which a melee weapon can strike, in cells.  Close combat is reach 0 ("C").
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Reach {
+    min: usize,
+    max: usize,
+}
+
+impl Reach {
+    pub fn contains(&self, distance: usize) -> bool {
+        (self.min..=self.max).contains(&distance)
+    }
+}
+
+impl Default for Reach {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+/// A tactical combat map.  Cells not otherwise marked are normal terrain.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Grid {
+    kind: GridKind,
+    terrain: BTreeMap<Position, Terrain>,
+}
+
+impl Grid {
+    pub fn new(kind: GridKind) -> Self {
+        Self {
+            kind,
+            terrain: BTreeMap::new(),
+        }
+    }
+
+    pub fn set_terrain(&mut self, position: Position, terrain: Terrain) {
+        self.terrain.insert(position, terrain);
+    }
+
+    pub fn terrain_at(&self, position: &Position) -> Terrain {
+        self.terrain.get(position).copied().unwrap_or_default()
+    }
+
+    pub fn distance(&self, a: &Position, b: &Position) -> usize {
+        self.kind.distance(a, b)
+    }
+
+    /// The cell adjacent to `position` in the direction of `facing`.
+    pub fn neighbor(&self, position: &Position, facing: usize) -> Position {
+        let directions = self.kind.directions();
+        position.offset(directions[facing % directions.len()])
+    }
+
+    /// Facing that points most directly from `from` toward `to`.
+    pub fn direction(&self, from: &Position, to: &Position) -> usize {
+        (0..self.kind.directions().len())
+            .min_by_key(|facing| self.distance(&self.neighbor(from, *facing), to))
+            .unwrap_or_default()
+    }
+
+    /// Whether `to` lies in the front arc of a combatant at `from` facing `facing`: the three
+    /// cells ahead and everything beyond them. BS-385
+    pub fn in_front(&self, from: &Position, facing: usize, to: &Position) -> bool {
+        from == to || self.kind.turn(facing, self.direction(from, to)) <= 1
+    }
+
+    /// Whether a combatant at `from` facing `facing` can strike `to` with `reach`.
+    pub fn in_reach(&self, from: &Position, facing: usize, to: &Position, reach: &Reach) -> bool {
+        reach.contains(self.distance(from, to)) && self.in_front(from, facing, to)
+    }
+
+    /// Movement points to enter the adjacent cell `to` from `from` while facing `facing`.
+    /// Moving forward costs 1, sideways or backward 2, multiplied by the terrain entered.
+    /// `None` if the cell is not adjacent or cannot be entered. BS-386
+    pub fn move_cost(&self, from: &Position, facing: usize, to: &Position) -> Option<usize> {
+        if self.distance(from, to) != 1 {
+            return None;
+        }
+        let terrain = self.terrain_at(to).cost()?;
+        let direction = self.direction(from, to);
+        let base = if direction == facing % self.kind.directions().len() {
+            1
+        } else {
+            2
+        };
+        Some(base * terrain)
+    }
+
+    /// Movement points to turn from facing `from` to facing `to`, one per side turned. BS-386
+    pub fn turn_cost(&self, from: usize, to: usize) -> usize {
+        self.kind.turn(from, to)
+    }
+
+    /// Every cell a combatant at `from` can reach with `points` of movement, with the cost of
+    /// the cheapest route there.  Routes are costed as forward movement, turning as they go.
+    pub fn reachable(&self, from: &Position, points: usize) -> BTreeMap<Position, usize> {
+        let mut costs = BTreeMap::from([(*from, 0)]);
+        let mut queue = BinaryHeap::from([std::cmp::Reverse((0, *from))]);
+        while let Some(std::cmp::Reverse((cost, position))) = queue.pop() {
+            if costs.get(&position).is_some_and(|c| *c < cost) {
+                continue;
+            }
+            for facing in 0..self.kind.directions().len() {
+                let next = self.neighbor(&position, facing);
+                let Some(step) = self.terrain_at(&next).cost() else {
+                    continue;
+                };
+                let total = cost + step;
+                if total <= points && costs.get(&next).is_none_or(|c| total < *c) {
+                    costs.insert(next, total);
+                    queue.push(std::cmp::Reverse((total, next)));
+                }
+            }
+        }
+        costs
+    }
+
+    /// Whether a combatant at `from` can cover the distance to strike `target` with `reach`
+    /// using no more than `points` of movement, as in a Move and Attack.
+    pub fn can_close(
+        &self,
+        from: &Position,
+        target: &Position,
+        reach: &Reach,
+        points: usize,
+    ) -> bool {
+        self.reachable(from, points)
+            .keys()
+            .any(|position| reach.contains(self.distance(position, target)))
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{ArmorPiece, Shield, Weapon};
+
+/// Where on the body an item is carried.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Carried {
+    /// Held ready in hand.
+    Hand,
+    /// Hung from a belt, strap or scabbard.
+    Belt,
+    /// Slung across the back.
+    Back,
+    #[default]
+    Pack,
+    Pocket,
+}
+
+/// Anything that can be carried.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Item {
+    name: String,
+    /// Pounds.
+    weight: f64,
+    /// Dollars.
+    cost: usize,
+}
+
+impl From<&Weapon> for Item {
+    fn from(weapon: &Weapon) -> Self {
+        Self::new(weapon.name().clone(), *weapon.weight(), *weapon.cost())
+    }
+}
+
+impl From<&Shield> for Item {
+    fn from(shield: &Shield) -> Self {
+        Self::new(shield.name().clone(), *shield.weight(), *shield.cost())
+    }
+}
+
+impl From<&ArmorPiece> for Item {
+    fn from(piece: &ArmorPiece) -> Self {
+        Self::new(piece.name().clone(), *piece.weight(), *piece.cost())
+    }
+}
+
+/// A number of the same item, carried in one place.  Containers hold further entries.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Entry {
+    item: Item,
+    quantity: usize,
+    carried: Carried,
+    /// Pounds the item can hold, if it is a container.
+    capacity: Option<f64>,
+    contents: Vec<Entry>,
+}
+
+impl Entry {
+    pub fn new(item: Item, quantity: usize, carried: Carried) -> Self {
+        Self {
+            item,
+            quantity,
+            carried,
+            capacity: None,
+            contents: Vec::new(),
+        }
+    }
+
+    pub fn container(item: Item, capacity: f64, carried: Carried) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::new(item, 1, carried)
+        }
+    }
+
+    /// Pounds, including anything inside.
+    pub fn weight(&self) -> f64 {
+        self.item.weight * self.quantity as f64 + self.contents_weight()
+    }
+
+    /// Dollars, including anything inside.
+    pub fn cost(&self) -> usize {
+        self.item.cost * self.quantity + self.contents.iter().map(|e| e.cost()).sum::<usize>()
+    }
+
+    fn contents_weight(&self) -> f64 {
+        self.contents.iter().map(|e| e.weight()).sum()
+    }
+
+    fn find_mut(&mut self, name: &str) -> Option<&mut Self> {
+        if self.item.name == name {
+            return Some(self);
+        }
+        self.contents.iter_mut().find_map(|e| e.find_mut(name))
+    }
+
+    /// Removes up to `quantity` of the item named `name` from among the contents.
+    fn remove(&mut self, name: &str, quantity: usize) -> Option<Entry> {
+        remove_from(&mut self.contents, name, quantity)
+    }
+}
+
+/// Removes up to `quantity` of the item named `name` from `entries`, searching containers.
+fn remove_from(entries: &mut Vec<Entry>, name: &str, quantity: usize) -> Option<Entry> {
+    match entries.iter().position(|e| e.item.name == name) {
+        Some(index) if entries[index].quantity > quantity => {
+            let entry = &mut entries[index];
+            entry.quantity -= quantity;
+            Some(Entry::new(entry.item.clone(), quantity, entry.carried))
+        }
+        Some(index) => Some(entries.remove(index)),
+        None => entries.iter_mut().find_map(|e| e.remove(name, quantity)),
+    }
+}
+
+/// Reason an item cannot be stored or dropped.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::Error,
+)]
+pub enum InventoryError {
+    #[display("Not carrying {_0}")]
+    NotCarried(#[error(not(source))] String),
+    #[display("{_0} is not a container")]
+    NotContainer(#[error(not(source))] String),
+    #[display("{container} can hold {capacity} lbs, not {weight} lbs")]
+    OverCapacity {
+        container: String,
+        capacity: f64,
+        weight: f64,
+    },
+}
+
+/// Everything a character carries, other than the armor they wear. BS-17
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Inventory {
+    entries: Vec<Entry>,
+}
+
+impl Inventory {
+    /// Carries `quantity` of `item`, adding to any of the same item already carried there.
+    pub fn add(&mut self, item: Item, quantity: usize, carried: Carried) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.item == item && e.carried == carried && e.capacity.is_none())
+        {
+            Some(entry) => entry.quantity += quantity,
+            None => self.entries.push(Entry::new(item, quantity, carried)),
+        }
+    }
+
+    /// Carries an empty container that can hold `capacity` pounds.
+    pub fn add_container(&mut self, item: Item, capacity: f64, carried: Carried) {
+        self.entries.push(Entry::container(item, capacity, carried));
+    }
+
+    /// Stores `quantity` of `item` in the container named `container`.
+    pub fn store(
+        &mut self,
+        container: &str,
+        item: Item,
+        quantity: usize,
+    ) -> Result<(), InventoryError> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find_map(|e| e.find_mut(container))
+            .ok_or_else(|| InventoryError::NotCarried(container.to_string()))?;
+        let capacity = entry
+            .capacity
+            .ok_or_else(|| InventoryError::NotContainer(container.to_string()))?;
+        let weight = entry.contents_weight() + item.weight * quantity as f64;
+        if weight > capacity {
+            return Err(InventoryError::OverCapacity {
+                container: container.to_string(),
+                capacity,
+                weight,
+            });
+        }
+        let carried = entry.carried;
+        match entry.contents.iter_mut().find(|e| e.item == item) {
+            Some(stored) => stored.quantity += quantity,
+            None => entry.contents.push(Entry::new(item, quantity, carried)),
+        }
+        Ok(())
+    }
+
+    /// Drops up to `quantity` of the item named `name`, wherever it is carried, returning what
+    /// was dropped.  Dropping a container drops its contents with it.
+    pub fn drop(&mut self, name: &str, quantity: usize) -> Result<Entry, InventoryError> {
+        let dropped = remove_from(&mut self.entries, name, quantity)
+            .ok_or_else(|| InventoryError::NotCarried(name.to_string()))?;
+        tracing::trace!("Dropped {} {}.", dropped.quantity, dropped.item.name);
+        Ok(dropped)
+    }
+
+    /// Pounds carried.
+    pub fn weight(&self) -> f64 {
+        self.entries.iter().map(|e| e.weight()).sum()
+    }
+
+    /// Dollars.
+    pub fn cost(&self) -> usize {
+        self.entries.iter().map(|e| e.cost()).sum()
+    }
+}
@@ -1,23 +1,70 @@
 mod advantages;
+mod armor;
+mod bard;
 mod body;
+mod casting;
 mod character;
 mod cli;
+mod combat;
 mod dice;
 mod free;
+mod fright;
+mod grid;
+mod inventory;
+mod luck;
 pub mod movement;
 mod players;
+mod profile;
+mod ranged;
+mod ritual;
+mod sense;
+mod session;
 mod skills;
 mod special_features;
+mod spell_tree;
+mod spells;
+mod talent;
+mod weapon;
 
 pub use advantages::{
-    AbsoluteDirection, Advantage, Disadvantage, Duty, EiditicMemory, Luck, Perk, SenseOfDuty,
-    Wealth,
+    AbsoluteDirection, Advantage, Disadvantage, Duty, EiditicMemory, Luck, Perk, RollKind,
+    SenseOfDuty, TraitEffect, Wealth,
+};
+pub use armor::{Armor, ArmorError, ArmorPiece, Coverage};
+pub use bard::{BardSong, BARDIC_SKILLS};
+pub use body::{Arms, BodyArea, Head, HitLocation, Legs, Torso};
+pub use casting::{
+    long_distance_modifier, Backfire, CastResult, Casting, CastingError, MaintainedSpell, ManaLevel,
+};
+pub use character::{
+    AttributeType, Attributes, BaseDamage, Character, Condition, Encumbrance, EncumbranceLevel,
+    EncumbranceMove, Stats,
 };
-pub use body::BodyArea;
-pub use character::{AttributeType, Attributes, BaseDamage, CombatStats, Encumbrance, Stats};
 pub use cli::Cli;
-pub use dice::{DieLevel, Random};
+pub use combat::{AttackRoll, Combat, Combatant};
+pub use dice::{DieLevel, Outcome, QuickContest, Random, SuccessRoll, LOG_LIMIT};
 pub use free::trace_init;
+pub use fright::{FrightCheck, FrightEffect, FRIGHT_CHECK_MAX};
+pub use grid::{Grid, GridKind, Position, Reach, Terrain};
+pub use inventory::{Carried, Entry, Inventory, InventoryError, Item};
+pub use luck::{Clock, LuckError, LuckTracker, SystemClock};
 pub use players::Players;
-pub use skills::Skill;
+pub use profile::{defense_from_skill, AttackProfile, CombatProfile, WeaponProfile};
+pub use ranged::{
+    rapid_fire_bonus, size_modifier, speed_range_modifier, RangedAttack, RangedShot, RangedTarget,
+};
+pub use ritual::{
+    Effect, Gathering, Quirk, Realm, Ritual, RitualError, RitualModifier, Verb, CYCLE_SECONDS,
+};
+pub use sense::{Sense, SenseConditions};
+pub use session::{SessionEntry, SessionLog};
+pub use skills::{Difficulty, KnownSkill, Skill};
 pub use special_features::SpecialFeatures;
+pub use spell_tree::{PrerequisiteError, SpellTree};
+pub use spells::{
+    cost_reduction, College, Energy, KnownSpell, Prerequisite, Spell, SpellClass, SpellDuration,
+};
+pub use talent::Talent;
+pub use weapon::{
+    DamageType, Parry, Range, RangedStats, Shield, Weapon, WeaponAttack, WeaponDamage,
+};
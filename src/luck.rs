@@ -0,0 +1,108 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{Advantage, Character, Luck, Random, SessionLog, SuccessRoll};
+
+/// Source of the current time, so cooldowns can be checked against a fixed clock.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+/// The system's wall clock.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Reason Luck cannot be used.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::Error,
+)]
+pub enum LuckError {
+    #[display("Luck can be used again in {} minutes.", remaining.as_secs().div_ceil(60))]
+    Cooldown { remaining: Duration },
+    #[display("There is no roll {_0} in the log.")]
+    NoRoll(#[error(not(source))] usize),
+}
+
+/// A character's Luck and when they last used it. BS-66
+#[derive(Debug, Clone, PartialEq, Eq, derive_getters::Getters)]
+pub struct LuckTracker<C: Clock> {
+    name: String,
+    luck: Luck,
+    last_used: Option<SystemTime>,
+    #[getter(skip)]
+    clock: C,
+}
+
+impl<C: Clock> LuckTracker<C> {
+    pub fn new(name: &str, luck: Luck, clock: C) -> Self {
+        Self {
+            name: name.to_string(),
+            luck,
+            last_used: None,
+            clock,
+        }
+    }
+
+    /// Tracks the character's Luck, if they have it.
+    pub fn from_character(character: &Character, clock: C) -> Option<Self> {
+        match character.advantage(&Advantage::Luck(Luck::default()))? {
+            Advantage::Luck(luck) => Some(Self::new(character.name(), *luck, clock)),
+            _ => None,
+        }
+    }
+
+    /// Real time left before Luck can be used again.
+    pub fn remaining(&self) -> Duration {
+        let Some(last_used) = self.last_used else {
+            return Duration::ZERO;
+        };
+        let elapsed = self
+            .clock
+            .now()
+            .duration_since(last_used)
+            .unwrap_or_default();
+        self.luck.cooldown().saturating_sub(elapsed)
+    }
+
+    /// Rolls the logged roll at `index` twice more and keeps the best of the three, recording the
+    /// use in `session`.
+    pub fn reroll(
+        &mut self,
+        index: usize,
+        random: &mut Random,
+        session: &mut SessionLog,
+    ) -> Result<SuccessRoll, LuckError> {
+        let remaining = self.remaining();
+        if !remaining.is_zero() {
+            return Err(LuckError::Cooldown { remaining });
+        }
+        let original = *random.log().get(index).ok_or(LuckError::NoRoll(index))?;
+        let best = random.reroll(index, 2).ok_or(LuckError::NoRoll(index))?;
+        let now = self.clock.now();
+        self.last_used = Some(now);
+        let event = format!(
+            "uses Luck on a roll of {} against {}, keeping {}.",
+            original.roll(),
+            original.target(),
+            best.roll()
+        );
+        session.record(now, &self.name, &event);
+        Ok(best)
+    }
+}
@@ -1,10 +1,50 @@
 use clap::Parser;
-use valinoreth::{trace_init, Cli, Players, Random};
+use valinoreth::{
+    trace_init, ArmorPiece, Cli, Combat, LuckTracker, Players, Random, SessionLog, Shield, Spell,
+    SpellTree, SystemClock, Weapon,
+};
 
 fn main() {
     trace_init();
     let cli = Cli::parse();
     match cli.command().as_str() {
+        "armor" => match ArmorPiece::catalog() {
+            Ok(pieces) => {
+                for piece in pieces {
+                    tracing::info!("{}: DR {}", piece.name(), piece.dr());
+                }
+            }
+            Err(e) => tracing::error!("Could not read catalog: {}", e),
+        },
+        "combat" => {
+            let mut random = Random::default();
+            let characters = vec![Players::paeva_character(), Players::tanithas_character()];
+            for character in &characters {
+                tracing::info!("{}", character.profile());
+            }
+            let mut combat = Combat::new(characters, &mut random);
+            let rounds = combat.combatants().len() * 3;
+            for _ in 0..rounds {
+                if let Some(combatant) = combat.current() {
+                    tracing::info!("Second {}: {}'s turn.", combat.second(), combatant.name());
+                }
+                combat.advance();
+            }
+        }
+        "luck" => {
+            let mut random = Random::default();
+            let mut session = SessionLog::default();
+            let character = Players::paeva_character();
+            if let Some(mut luck) = LuckTracker::from_character(&character, SystemClock) {
+                random.success_roll(10);
+                for _ in 0..2 {
+                    match luck.reroll(0, &mut random, &mut session) {
+                        Ok(roll) => tracing::info!("Kept {}.", roll.roll()),
+                        Err(e) => tracing::info!("{}", e),
+                    }
+                }
+            }
+        }
         "paeva" => Players::paeva(),
         "prob" => {
             let prob = bio::stats::combinatorics::combinations_with_repl(6, 3);
@@ -14,7 +54,37 @@ fn main() {
             let mut random = Random::default();
             tracing::info!("Roll is {}", random.roll());
         }
+        "spells" => {
+            let character = Players::tanithas_character();
+            for spell in [
+                Spell::Fireball,
+                Spell::MajorHealing,
+                Spell::DispelMagic,
+                Spell::Lightning,
+            ] {
+                match SpellTree::path_to(&character, spell) {
+                    Some(path) => tracing::info!("{}: {:?}", spell, path),
+                    None => tracing::info!("{} cannot be learned.", spell),
+                }
+            }
+        }
         "tanithas" => Players::tanithas(),
+        "weapons" => match (Weapon::catalog(), Shield::catalog()) {
+            (Ok(weapons), Ok(shields)) => {
+                for weapon in weapons {
+                    let damage = weapon
+                        .attacks()
+                        .iter()
+                        .map(|a| format!("{} {}", a.damage(), a.damage_type()))
+                        .collect::<Vec<String>>();
+                    tracing::info!("{}: {}", weapon.name(), damage.join(", "));
+                }
+                for shield in shields {
+                    tracing::info!("{}: DB {}", shield.name(), shield.db());
+                }
+            }
+            (Err(e), _) | (_, Err(e)) => tracing::error!("Could not read catalog: {}", e),
+        },
         _ => tracing::info!("Command not recognized."),
     };
 }
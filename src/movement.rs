@@ -1,3 +1,10 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    Advantage, AttributeType, Character, DieLevel, Encumbrance, EncumbranceLevel, Position,
+    QuickContest, Random,
+};
+
 /// Combat time occurs second by second. BS-362
 /// Multiple partipants experience combat as overlapping seconds
 /// Because they take actions in a turn order. BS-363
@@ -6,6 +13,20 @@
 ///
 /// Maximum move is the characters full Move score.
 /// A "step" is 1/10 of Move, minimum one meter. BS-363
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
 pub enum Manuever {
     /// Full-turn maneuver.
     /// Bracing a ranged weapon adds +1 to Acc, meaning resting a sandbag, low wall, etc.  A
@@ -19,12 +40,19 @@ pub enum Manuever {
     Aim,
     /// No active defense.  No dodge, parry or block.
     /// Move up to half Move, only forward.
-    AllOutAttack,
-    AllOutDefense,
+    #[display("AllOutAttack({_0})")]
+    AllOutAttack(AllOutAttack),
+    /// Increase defenses at the cost of any attack. BS-366
+    /// Step movement, or half Move with Increased Dodge.
+    #[display("AllOutDefense({_0})")]
+    AllOutDefense(AllOutDefense),
     /// Armed or unarmed attack against an opponent.  Weapons must be ready and targets must be in reach.
     /// May step and attack or attack then step.
     Attack,
+    /// Stand up, sit down, kneel, etc.  No movement.
     ChangePosture,
+    /// Focus on a mental task, such as casting a spell.  Any active defense, but a Will-3 roll
+    /// is needed to keep concentrating after defending or being injured. BS-366
     Concentrate,
     /// May attempt a HT roll to recover from physical stun or IQ roll to recover from mental stun,
     /// recovering at the end of the turn. BS-364
@@ -55,14 +83,541 @@ pub enum Manuever {
     /// Sprinting grants a bonus movement on the second and later moves.
     /// No other action but Free Actions. B-364
     Move,
+    /// Move up to full Move and attack during or after the movement.
+    /// Melee attacks are at -4 with effective skill capped at 9, ranged attacks at -2 or the
+    /// weapon's Bulk, whichever is worse.  No Parry. BS-365
     MoveAndAttack,
+    /// Ready a weapon or other item.  Step movement.
     Ready,
+    /// Hold the turn until a specified event occurs, then act. BS-366
+    /// The response may be an Attack, All-Out Attack, Feint or Ready, and interrupts the turn
+    /// order when it fires.  A Wait that has not fired by the next turn is lost.
     Wait,
 }
 
+impl Manuever {
+    /// Movement permitted during the maneuver.
+    pub fn movement(&self) -> MoveAllowance {
+        match self {
+            Self::AllOutAttack(_) => MoveAllowance::HalfForward,
+            Self::AllOutDefense(AllOutDefense::IncreasedDodge) => MoveAllowance::Half,
+            Self::ChangePosture => MoveAllowance::None,
+            Self::DoNothing => MoveAllowance::None,
+            Self::Move => MoveAllowance::Full,
+            Self::MoveAndAttack => MoveAllowance::Full,
+            _ => MoveAllowance::Step,
+        }
+    }
+
+    /// Active defenses available until the start of the next turn.
+    pub fn defenses(&self) -> Vec<ActiveDefense> {
+        match self {
+            Self::AllOutAttack(_) => Vec::new(),
+            Self::MoveAndAttack => vec![ActiveDefense::Dodge, ActiveDefense::Block],
+            _ => ActiveDefense::iter().collect(),
+        }
+    }
+
+    pub fn allows_defense(&self, defense: &ActiveDefense) -> bool {
+        self.defenses().contains(defense)
+    }
+
+    /// Bonus to an active defense from the maneuver.
+    pub fn defense_bonus(&self, defense: &ActiveDefense) -> i64 {
+        match self {
+            Self::AllOutDefense(option) => option.bonus(defense),
+            _ => 0,
+        }
+    }
+
+    /// Number of different active defenses that may be tried against a single attack.
+    pub fn defenses_per_attack(&self) -> usize {
+        match self {
+            Self::AllOutDefense(AllOutDefense::DoubleDefense) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Whether the maneuver includes an attack.  A triggered Wait attacks through its response.
+    pub fn allows_attack(&self) -> bool {
+        matches!(
+            self,
+            Self::AllOutAttack(_) | Self::Attack | Self::MoveAndAttack
+        )
+    }
+
+    /// Whether the maneuver includes a Feint.
+    pub fn allows_feint(&self) -> bool {
+        matches!(
+            self,
+            Self::Feint | Self::AllOutAttack(AllOutAttack::Melee(AllOutMeleeAttack::Feint))
+        )
+    }
+
+    /// Number of attacks the maneuver allows.
+    pub fn attacks(&self) -> usize {
+        match self {
+            Self::AllOutAttack(option) => option.attacks(),
+            _ if self.allows_attack() => 1,
+            _ => 0,
+        }
+    }
+
+    /// Modifier to hit for an attack made as part of the maneuver, with a weapon of `bulk` for
+    /// ranged attacks.
+    pub fn attack_modifier(&self, kind: &AttackKind, bulk: i64) -> i64 {
+        match (self, kind) {
+            (Self::AllOutAttack(option), _) => option.attack_modifier(),
+            (Self::MoveAndAttack, AttackKind::Melee) => -4,
+            (Self::MoveAndAttack, AttackKind::Ranged) => bulk.min(-2),
+            _ => 0,
+        }
+    }
+
+    /// Damage for an attack made as part of the maneuver, given the attack's base damage.
+    pub fn damage(&self, damage: &DieLevel) -> DieLevel {
+        match self {
+            Self::AllOutAttack(AllOutAttack::Melee(option)) => option.damage(damage),
+            _ => *damage,
+        }
+    }
+
+    /// Maximum effective skill for an attack made as part of the maneuver, if limited.
+    pub fn skill_cap(&self, kind: &AttackKind) -> Option<i64> {
+        match (self, kind) {
+            (Self::MoveAndAttack, AttackKind::Melee) => Some(9),
+            _ => None,
+        }
+    }
+
+    /// Checks that `action` may be taken during the maneuver, given the character's Move and
+    /// the distance already covered this turn.
+    pub fn validate(
+        &self,
+        action: &Action,
+        basic_move: usize,
+        moved: usize,
+    ) -> Result<(), ManueverError> {
+        match action {
+            Action::Move(meters) => {
+                let allowed = self.movement().meters(basic_move);
+                if moved + meters > allowed {
+                    return Err(ManueverError::TooFar {
+                        manuever: *self,
+                        allowed,
+                        requested: moved + meters,
+                    });
+                }
+            }
+            Action::Attack(kind) => {
+                if !self.allows_attack() {
+                    return Err(ManueverError::NoAttack(*self));
+                }
+                if let Self::AllOutAttack(option) = self {
+                    if option.kind() != *kind {
+                        return Err(ManueverError::WrongAttack(*self, *kind));
+                    }
+                }
+            }
+            Action::Defend(defense) => {
+                if !self.allows_defense(defense) {
+                    return Err(ManueverError::NoDefense(*self, *defense));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Aim bonus accumulated over consecutive [`Manuever::Aim`] turns against one target. BS-364
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Aim {
+    /// Combatant being aimed at.
+    target: usize,
+    /// Consecutive seconds spent aiming.
+    seconds: usize,
+    braced: bool,
+}
+
+impl Aim {
+    /// Spends another second aiming at the same target.
+    pub fn extend(&mut self) {
+        self.seconds += 1;
+    }
+
+    /// Bonus to hit with a weapon of accuracy `acc`.
+    /// Bracing adds +1, as do two seconds of aim, three or more add +2.  The bonus from bracing
+    /// and extra seconds cannot exceed the weapon's base Accuracy.
+    pub fn bonus(&self, acc: i64) -> i64 {
+        let extra = match self.seconds {
+            0 | 1 => 0,
+            2 => 1,
+            _ => 2,
+        };
+        let brace = if self.braced { 1 } else { 0 };
+        acc + (extra + brace).min(acc)
+    }
+}
+
+/// Evaluate bonus accumulated over consecutive [`Manuever::Evaluate`] turns. BS-364
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Evaluate {
+    /// Combatant being evaluated.
+    target: usize,
+    turns: usize,
+}
+
+impl Evaluate {
+    pub fn extend(&mut self) {
+        self.turns += 1;
+    }
+
+    /// +1 per turn of Evaluate, maximum +3.
+    pub fn bonus(&self) -> i64 {
+        self.turns.min(3) as i64
+    }
+}
+
+/// Penalty a successful [`Manuever::Feint`] leaves on the foe's defenses against the feinter.
+/// Good only for the feinter's next turn, or the attack following an All-Out Attack (Feint), and
+/// for both attacks of an All-Out Attack (Double). BS-365
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Feint {
+    /// Combatant who made the feint.
+    by: usize,
+    penalty: i64,
+    /// Second of combat in which the feint was made.
+    second: usize,
+}
+
+impl Feint {
+    /// Penalty from the Quick Contest between the feinter's roll and the foe's.
+    ///
+    /// * Feinter fails, or foe succeeds by as much - no penalty.
+    /// * Foe fails - the feinter's margin of success.
+    /// * Foe succeeds by less - the difference in margins.
+    pub fn penalty_from(contest: &QuickContest) -> i64 {
+        let feinter = contest.first();
+        let foe = contest.second();
+        if !feinter.is_success() {
+            0
+        } else if foe.is_success() {
+            contest.margin().max(0)
+        } else {
+            *feinter.margin()
+        }
+    }
+
+    /// Whether the feint still applies in `second`.
+    pub fn is_current(&self, second: usize) -> bool {
+        second <= self.second + 1
+    }
+}
+
+/// A hold on a combatant by a foe in close combat.  The victim is at -4 DX while grappled, and
+/// cannot move away or defend at all once pinned. BS-370
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Grapple {
+    /// Combatant holding the victim.
+    by: usize,
+    pinned: bool,
+}
+
+impl Grapple {
+    /// Penalty to DX and DX-based skills while grappled.
+    pub const DX_PENALTY: i64 = -4;
+
+    pub fn pin(&mut self) {
+        self.pinned = true;
+    }
+}
+
+/// Distance a [`Manuever`] lets a combatant cover on their turn.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum MoveAllowance {
+    None,
+    /// 1/10 of Move, minimum one meter. BS-363
+    Step,
+    /// Up to half Move, forward only.
+    HalfForward,
+    /// Up to half Move in any direction.
+    Half,
+    Full,
+}
+
+impl MoveAllowance {
+    /// Meters allowed for a character with the given Move.
+    pub fn meters(&self, basic_move: usize) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Step => (basic_move / 10).max(1),
+            Self::HalfForward | Self::Half => basic_move / 2,
+            Self::Full => basic_move,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum ActiveDefense {
+    Dodge,
+    Parry,
+    Block,
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum AttackKind {
+    Melee,
+    Ranged,
+}
+
+/// Something a combatant does during a [`Manuever`] that the maneuver may forbid.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum Action {
+    /// Move the given number of meters.
+    #[display("Move({_0})")]
+    Move(usize),
+    #[display("Attack({_0})")]
+    Attack(AttackKind),
+    #[display("Defend({_0})")]
+    Defend(ActiveDefense),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum ManueverError {
+    #[display("No maneuver has been declared.")]
+    Undeclared,
+    #[display("{_0} does not allow an attack.")]
+    NoAttack(#[error(not(source))] Manuever),
+    #[display("{_0} does not allow a {_1}.")]
+    NoDefense(Manuever, ActiveDefense),
+    #[display("{_0} does not allow a {_1} attack.")]
+    WrongAttack(Manuever, AttackKind),
+    #[display("There is no combatant {_0}.")]
+    NoTarget(#[error(not(source))] usize),
+    #[display("{_0} does not allow a feint.")]
+    NoFeint(#[error(not(source))] Manuever),
+    #[display("{_0} allows only {_1} attacks.")]
+    NoAttacksLeft(Manuever, usize),
+    #[display("{_0} does not allow a second defense against the same attack.")]
+    NoSecondDefense(#[error(not(source))] Manuever),
+    #[display("{_0} has already been tried against this attack.")]
+    RepeatedDefense(#[error(not(source))] ActiveDefense),
+    #[display("Cannot move into {_0}.")]
+    Blocked(#[error(not(source))] Position),
+    #[display("{_0} is not a valid response to a Wait.")]
+    WaitResponse(#[error(not(source))] Manuever),
+    #[display("{_0} may only be taken {_1} times per turn.")]
+    FreeActionLimit(FreeAction, usize),
+    #[display("Not grappling combatant {_0}.")]
+    NotGrappling(#[error(not(source))] usize),
+    #[display("Not grappled by anyone.")]
+    NotGrappled,
+    #[display("Cannot move while grappled by combatant {_0}.")]
+    Grappled(#[error(not(source))] usize),
+    #[display("Cannot defend while pinned.")]
+    Pinned,
+    #[display("Combatant {_0} must be on the ground to be pinned.")]
+    NotDown(#[error(not(source))] usize),
+    #[display("{manuever} does not allow changing from {from} to {to}.")]
+    Posture {
+        manuever: Manuever,
+        from: Posture,
+        to: Posture,
+    },
+    #[display("{manuever} needs RoF {required}, not {rof}.")]
+    RateOfFire {
+        manuever: Manuever,
+        required: usize,
+        rof: usize,
+    },
+    #[display("{manuever} allows {allowed} meters of movement, not {requested}.")]
+    TooFar {
+        manuever: Manuever,
+        allowed: usize,
+        requested: usize,
+    },
+}
+
+/// All-Out Attack, either melee or ranged. BS-365
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum AllOutAttack {
+    #[display("Melee({_0})")]
+    Melee(AllOutMeleeAttack),
+    #[display("Ranged({_0})")]
+    Ranged(AllOutRangedAttack),
+}
+
+impl Default for AllOutAttack {
+    fn default() -> Self {
+        Self::Melee(AllOutMeleeAttack::default())
+    }
+}
+
+impl AllOutAttack {
+    pub fn kind(&self) -> AttackKind {
+        match self {
+            Self::Melee(_) => AttackKind::Melee,
+            Self::Ranged(_) => AttackKind::Ranged,
+        }
+    }
+
+    pub fn attack_modifier(&self) -> i64 {
+        match self {
+            Self::Melee(AllOutMeleeAttack::Determined) => 4,
+            Self::Ranged(AllOutRangedAttack::Determined) => 1,
+            _ => 0,
+        }
+    }
+
+    /// Attacks made during the maneuver.  The Feint option spends one of its two actions on the
+    /// feint.
+    pub fn attacks(&self) -> usize {
+        match self {
+            Self::Melee(AllOutMeleeAttack::Double) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Minimum weapon RoF needed for the option.
+    pub fn min_rof(&self) -> usize {
+        match self {
+            Self::Ranged(AllOutRangedAttack::SuppressionFire) => 5,
+            _ => 1,
+        }
+    }
+}
+
 /// All-Out Attack options for melee attack. BS-365
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
 pub enum AllOutMeleeAttack {
     /// Make a single attack at +4 to hit.
+    #[default]
     Determined,
     /// Make two attacks against the same foe.
     ///
@@ -77,15 +632,81 @@ pub enum AllOutMeleeAttack {
     Strong,
 }
 
+impl AllOutMeleeAttack {
+    /// Damage for an attack made with the option, given the attack's base damage.
+    pub fn damage(&self, damage: &DieLevel) -> DieLevel {
+        match self {
+            Self::Strong => {
+                let per_die = *damage.dice();
+                damage.with_pips(per_die.max(2))
+            }
+            _ => *damage,
+        }
+    }
+}
+
 /// All-Out Attack options for ranged attack. BS-365
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
 pub enum AllOutRangedAttack {
     /// Make a single attack at +1 to hit.
+    #[default]
     Determined,
     /// Full-turn maneuver.
     /// Weapon RoF 5+ required.
     SuppressionFire,
 }
 
+/// All-Out Defense options. BS-366
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum AllOutDefense {
+    /// +2 to Dodge.  Move up to half Move.
+    #[default]
+    IncreasedDodge,
+    /// +2 to Parry.  Step movement.
+    IncreasedParry,
+    /// +2 to Block.  Step movement.
+    IncreasedBlock,
+    /// Try a second, different active defense against an attack after the first fails.
+    DoubleDefense,
+}
+
+impl AllOutDefense {
+    pub fn bonus(&self, defense: &ActiveDefense) -> i64 {
+        match (self, defense) {
+            (Self::IncreasedDodge, ActiveDefense::Dodge) => 2,
+            (Self::IncreasedParry, ActiveDefense::Parry) => 2,
+            (Self::IncreasedBlock, ActiveDefense::Block) => 2,
+            _ => 0,
+        }
+    }
+}
+
 pub enum Success {
     /// A skill check against a set level or threshold.
     Check,
@@ -93,24 +714,333 @@ pub enum Success {
     Margin,
 }
 
+/// Actions that may be taken during any maneuver, even on another combatant's turn. BS-363
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
 pub enum FreeAction {
+    /// One sentence per second.
     Talk,
     MaintainSpell,
     DropItem,
+    /// Crouch while standing, or rise from a crouch.
     Crouch,
 }
 
+impl FreeAction {
+    /// Times the action may be taken each turn, if limited.
+    pub fn limit(&self) -> Option<usize> {
+        match self {
+            Self::Talk => Some(1),
+            Self::Crouch => Some(1),
+            Self::MaintainSpell | Self::DropItem => None,
+        }
+    }
+}
+
+/// Event that fires a [`Manuever::Wait`].  Combatants are identified by their index in the
+/// [`Combat`](crate::Combat).
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum Trigger {
+    /// The combatant steps into reach of the waiting combatant.
+    #[display("Approaches({_0})")]
+    Approaches(usize),
+    /// The combatant begins an attack.
+    #[display("Attacks({_0})")]
+    Attacks(usize),
+    /// The combatant moves.
+    #[display("Moves({_0})")]
+    Moves(usize),
+    /// Any other condition, fired by name.
+    #[display("{_0}")]
+    Custom(String),
+}
+
+/// A held action: if `trigger` occurs, take the `response` maneuver immediately. BS-366
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Wait {
+    trigger: Trigger,
+    response: Manuever,
+    /// Set a thrusting weapon against a foe moving in.  The foe's approach adds to the damage of
+    /// the thrust: +1 for every two meters the foe moved toward the waiting combatant.
+    stop_thrust: bool,
+}
+
+impl Wait {
+    pub fn new(
+        trigger: Trigger,
+        response: Manuever,
+        stop_thrust: bool,
+    ) -> Result<Self, ManueverError> {
+        let allowed = matches!(
+            response,
+            Manuever::Attack | Manuever::AllOutAttack(_) | Manuever::Feint | Manuever::Ready
+        );
+        if !allowed {
+            return Err(ManueverError::WaitResponse(response));
+        }
+        if stop_thrust && !response.allows_attack() {
+            return Err(ManueverError::NoAttack(response));
+        }
+        Ok(Self {
+            trigger,
+            response,
+            stop_thrust,
+        })
+    }
+
+    /// Bonus damage from a Stop Thrust against a foe that moved `approach` meters toward the
+    /// waiting combatant.
+    pub fn stop_thrust_bonus(&self, approach: usize) -> i64 {
+        if self.stop_thrust {
+            (approach / 2) as i64
+        } else {
+            0
+        }
+    }
+}
+
 /// If you are lying (prone or face up), you must take a Change Poture maneuver to rise to a
 /// crawling, kneeling, or sitting posture first.  A second Change Psoture maneuver lets you stand
 /// from any of these postures.  Going from standing up to lying down only takes one manuever.
 /// You can switch between kneeling and standing (only) as the "step" portion of any maneuver that
 /// allows a step instead of using the step to move.
 /// Crouching does not require a Change Posture maneuver, it is a free action. B-364
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
 pub enum Posture {
+    #[default]
     Standing,
+    Crouching,
     Sitting,
     Kneeling,
     Crawling,
     LyingProne,
     LyingFaceDown,
 }
+
+impl Posture {
+    /// Lying is lowest, then crawling, kneeling and sitting, then standing and crouching.
+    fn height(&self) -> usize {
+        match self {
+            Self::LyingProne | Self::LyingFaceDown => 0,
+            Self::Sitting | Self::Kneeling | Self::Crawling => 1,
+            Self::Standing | Self::Crouching => 2,
+        }
+    }
+
+    /// Whether the change can be made as a free action.
+    pub fn is_free(&self, to: &Self) -> bool {
+        self == to
+            || matches!(
+                (self, to),
+                (Self::Standing, Self::Crouching) | (Self::Crouching, Self::Standing)
+            )
+    }
+
+    /// Whether the change can be made in place of the step of a maneuver.
+    pub fn is_step(&self, to: &Self) -> bool {
+        matches!(
+            (self, to),
+            (Self::Standing, Self::Kneeling) | (Self::Kneeling, Self::Standing)
+        )
+    }
+
+    /// Number of Change Posture maneuvers needed to go from this posture to `to`.
+    pub fn maneuvers_to(&self, to: &Self) -> usize {
+        if self.is_free(to) {
+            0
+        } else if to.height() > self.height() {
+            // Rising takes one maneuver per level.
+            to.height() - self.height()
+        } else {
+            // Getting down to any lower posture, or between postures of the same height.
+            1
+        }
+    }
+
+    /// Modifier to melee and ranged attacks made from the posture. BS-551
+    /// Crawling or lying, most melee weapons cannot be used at all.
+    pub fn attack(&self) -> i64 {
+        match self {
+            Self::Standing => 0,
+            Self::Crouching | Self::Kneeling | Self::Sitting => -2,
+            Self::Crawling | Self::LyingProne | Self::LyingFaceDown => -4,
+        }
+    }
+
+    /// Modifier to active defenses made from the posture. BS-551
+    pub fn defense(&self) -> i64 {
+        match self {
+            Self::Standing | Self::Crouching => 0,
+            Self::Kneeling | Self::Sitting => -2,
+            Self::Crawling | Self::LyingProne | Self::LyingFaceDown => -3,
+        }
+    }
+
+    /// Modifier to hit the character with a ranged attack. BS-551
+    pub fn to_be_hit(&self) -> i64 {
+        match self {
+            Self::Standing => 0,
+            _ => -2,
+        }
+    }
+
+    /// Move in the posture for a character with the given Move. BS-551
+    pub fn movement(&self, basic_move: usize) -> usize {
+        match self {
+            Self::Standing => basic_move,
+            Self::Crouching => basic_move * 2 / 3,
+            Self::Kneeling | Self::Crawling => basic_move / 3,
+            Self::Sitting => 0,
+            Self::LyingProne | Self::LyingFaceDown => 1,
+        }
+    }
+}
+
+/// Running over consecutive seconds of [`Manuever::Move`]. BS-354
+///
+/// * After the first second of running forward, sprinting adds 20% to Move, minimum +1.
+/// * Each level of Enhanced Move doubles top speed. BS-52
+/// * Every 15 seconds of sprinting calls for a HT roll, failure costs 1 FP. BS-354
+/// * Below 1/3 FP, Move is halved. BS-426
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Run {
+    /// Move after encumbrance.
+    base: usize,
+    /// Levels of Enhanced Move.
+    enhanced: usize,
+    /// Consecutive seconds spent moving forward at full Move.
+    running: usize,
+    /// Seconds spent sprinting since the last HT roll.
+    sprinting: usize,
+    /// Meters covered in each second of the run.
+    distances: Vec<usize>,
+}
+
+impl Run {
+    pub fn new(character: &Character, level: &EncumbranceLevel) -> Self {
+        let base = Encumbrance::from(character.stats()).enc_move().at(level);
+        let enhanced = character.advantage_level(&Advantage::EnhancedMove(0));
+        Self {
+            base,
+            enhanced,
+            ..Default::default()
+        }
+    }
+
+    /// Move for the next second, given whether the runner is sprinting and tired.
+    pub fn speed(&self, sprint: bool, tired: bool) -> usize {
+        let mut speed = self.base;
+        if sprint && self.running > 0 {
+            speed += (speed / 5).max(1);
+            let levels = u32::try_from(self.enhanced).unwrap_or(u32::MAX);
+            speed = speed.saturating_mul(2usize.saturating_pow(levels));
+        }
+        if tired {
+            speed /= 2;
+        }
+        speed
+    }
+
+    /// Runs for one second.  A `sprint` goes all-out in a straight line; anything else (turning,
+    /// dodging obstacles) covers normal Move and ends the sprint.  Returns the meters covered.
+    pub fn second(
+        &mut self,
+        sprint: bool,
+        character: &mut Character,
+        random: &mut Random,
+    ) -> usize {
+        let meters = self.speed(sprint, character.is_tired());
+        if sprint {
+            self.sprinting += 1;
+            self.running += 1;
+        } else {
+            self.running = 0;
+        }
+        if self.sprinting >= 15 {
+            self.sprinting = 0;
+            let ht = character.attributes().value(&AttributeType::Health) as i64;
+            if !random.success_roll(ht).is_success() {
+                character.spend_fatigue(1);
+            }
+        }
+        self.distances.push(meters);
+        meters
+    }
+
+    /// Sprints for `seconds`, returning the meters covered in each.
+    pub fn chase(
+        &mut self,
+        seconds: usize,
+        character: &mut Character,
+        random: &mut Random,
+    ) -> Vec<usize> {
+        (0..seconds)
+            .map(|_| self.second(true, character, random))
+            .collect()
+    }
+
+    /// Meters covered over the whole run.
+    pub fn total(&self) -> usize {
+        self.distances.iter().sum()
+    }
+}
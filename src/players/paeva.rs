@@ -1,16 +1,12 @@
 use crate::players::eponym;
 use crate::{
-    AbsoluteDirection, Advantage, Attributes, BaseDamage, Disadvantage, Duty, Encumbrance, Luck,
-    SenseOfDuty, Stats, Wealth,
+    AbsoluteDirection, Advantage, Attributes, BaseDamage, Character, Disadvantage, Duty,
+    Encumbrance, Luck, SenseOfDuty, SpecialFeatures, Wealth,
 };
 
 impl eponym::Players {
-    pub fn paeva() {
+    pub fn paeva_character() -> Character {
         let attributes = Attributes::from_vec(vec![8, 12, 11, 9, 9, 11, 11, 9]);
-        let stats = Stats::from(attributes);
-        let base_dmg = BaseDamage::from(attributes);
-        let encumbrance = Encumbrance::from(&stats);
-
         let advantages = vec![
             Advantage::AbsoluteDirection(AbsoluteDirection::Normal),
             Advantage::Ambidexterity,
@@ -21,17 +17,29 @@ impl eponym::Players {
             Advantage::Wealth(Wealth::VeryWealthy),
             Advantage::Voice,
         ];
+        let disadvantages = vec![
+            Disadvantage::Duty(Duty::FairlyOften),
+            Disadvantage::Selfless,
+            Disadvantage::SenseOfDuty(SenseOfDuty::LargeGroup),
+        ];
+        let special_features = SpecialFeatures::new(advantages, disadvantages, Vec::new());
+        Character::new("Paeva", attributes, special_features)
+    }
 
+    pub fn paeva() {
+        let character = Self::paeva_character();
+        let attributes = character.attributes();
+        let stats = character.stats();
+        let base_dmg = BaseDamage::from(*attributes);
+        let encumbrance = Encumbrance::from(stats);
+
+        let advantages = character.special_features().advantages();
         #[allow(clippy::unnecessary_fold)]
         let adv_cost = advantages
             .iter()
             .map(|v| v.cost())
             .fold(0, |sum, val| sum + val);
-        let disadvantages = vec![
-            Disadvantage::Duty(Duty::FairlyOften),
-            Disadvantage::Selfless,
-            Disadvantage::SenseOfDuty(SenseOfDuty::LargeGroup),
-        ];
+        let disadvantages = character.special_features().disadvantages();
         #[allow(clippy::unnecessary_fold)]
         let disadv_cost = disadvantages
             .iter()
@@ -1,14 +1,11 @@
 use crate::{
-    Advantage, Attributes, BaseDamage, Disadvantage, EiditicMemory, Encumbrance, Players, Stats,
+    Advantage, Attributes, BaseDamage, Character, Disadvantage, EiditicMemory, Encumbrance,
+    Players, SpecialFeatures,
 };
 
 impl Players {
-    pub fn tanithas() {
+    pub fn tanithas_character() -> Character {
         let attributes = Attributes::from_base(8, 8, 19, 8);
-        let stats = Stats::from(attributes);
-        let base_dmg = BaseDamage::from(attributes);
-        let encumbrance = Encumbrance::from(&stats);
-
         let advantages = vec![
             Advantage::Ambidexterity,
             Advantage::BardicTalent(3),
@@ -19,16 +16,29 @@ impl Players {
             Advantage::ReducedConsumption(2),
             Advantage::StableCasting,
         ];
-        #[allow(clippy::unnecessary_fold)]
-        let adv_cost = advantages
-            .iter()
-            .map(|v| v.cost())
-            .fold(0, |sum, val| sum + val);
         let disadvantages = vec![
             Disadvantage::Destiny(60),
             Disadvantage::SocialStigma(4),
             Disadvantage::Status(3),
         ];
+        let special_features = SpecialFeatures::new(advantages, disadvantages, Vec::new());
+        Character::new("Tanithas", attributes, special_features)
+    }
+
+    pub fn tanithas() {
+        let character = Self::tanithas_character();
+        let attributes = character.attributes();
+        let stats = character.stats();
+        let base_dmg = BaseDamage::from(*attributes);
+        let encumbrance = Encumbrance::from(stats);
+
+        let advantages = character.special_features().advantages();
+        #[allow(clippy::unnecessary_fold)]
+        let adv_cost = advantages
+            .iter()
+            .map(|v| v.cost())
+            .fold(0, |sum, val| sum + val);
+        let disadvantages = character.special_features().disadvantages();
         #[allow(clippy::unnecessary_fold)]
         let disadv_cost = disadvantages
             .iter()
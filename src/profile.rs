@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    BaseDamage, Character, DamageType, DieLevel, EncumbranceLevel, HitLocation, Reach, RollKind,
+    Skill, Weapon, WeaponAttack,
+};
+
+/// Active defense from a skill: 3 + half the skill, dropping fractions. BS-376
+pub fn defense_from_skill(level: i64) -> i64 {
+    3 + level.div_euclid(2)
+}
+
+/// One attack with a readied weapon, at the wielder's damage.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_more::Display,
+    derive_new::new,
+)]
+#[display("{damage} {damage_type}")]
+pub struct AttackProfile {
+    damage: DieLevel,
+    damage_type: DamageType,
+    reach: Option<Reach>,
+}
+
+impl AttackProfile {
+    pub fn from_attack(attack: &WeaponAttack, base: &BaseDamage) -> Self {
+        Self::new(
+            attack.damage_for(base),
+            *attack.damage_type(),
+            *attack.reach(),
+        )
+    }
+}
+
+/// A readied weapon as the wielder uses it.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct WeaponProfile {
+    name: String,
+    skill: Skill,
+    /// Effective level in `skill`.
+    level: i64,
+    attacks: Vec<AttackProfile>,
+    /// `None` for weapons that cannot parry.
+    parry: Option<i64>,
+}
+
+impl WeaponProfile {
+    pub fn from_weapon(weapon: &Weapon, character: &Character, db: i64) -> Self {
+        let base = BaseDamage::from(*character.attributes());
+        let level = character
+            .effective_skill(weapon.skill())
+            .unwrap_or_default();
+        let defense = character.roll_modifier(&RollKind::Defense);
+        let attacks = weapon
+            .attacks()
+            .iter()
+            .map(|a| AttackProfile::from_attack(a, &base))
+            .collect();
+        let parry = weapon
+            .parry()
+            .map(|p| defense_from_skill(level) + p.modifier() + db + defense);
+        Self::new(
+            weapon.name().clone(),
+            *weapon.skill(),
+            level,
+            attacks,
+            parry,
+        )
+    }
+}
+
+impl std::fmt::Display for WeaponProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let attacks = self
+            .attacks
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "{}-{} ({})", self.name, self.level, attacks.join(", "))?;
+        match self.parry {
+            Some(parry) => write!(f, " Parry {parry}"),
+            None => write!(f, " No Parry"),
+        }
+    }
+}
+
+/// Everything needed to play a character in combat, derived from their attributes, skills and
+/// equipment.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct CombatProfile {
+    name: String,
+    basic_damage: BaseDamage,
+    encumbrance: EncumbranceLevel,
+    /// Move at the current encumbrance.
+    current_move: usize,
+    /// Dodge at the current encumbrance, including any shield DB.
+    dodge: i64,
+    /// Block with the shield, including its DB.
+    block: Option<i64>,
+    weapons: Vec<WeaponProfile>,
+    dr: BTreeMap<HitLocation, usize>,
+}
+
+impl From<&Character> for CombatProfile {
+    fn from(character: &Character) -> Self {
+        let db = character
+            .shield()
+            .as_ref()
+            .map(|s| *s.db())
+            .unwrap_or_default();
+        let defense = character.roll_modifier(&RollKind::Defense);
+        let block = character.shield().as_ref().map(|_| {
+            let level = character
+                .effective_skill(&Skill::Shield)
+                .unwrap_or_default();
+            defense_from_skill(level) + db + defense
+        });
+        let weapons = character
+            .weapons()
+            .iter()
+            .map(|w| WeaponProfile::from_weapon(w, character, db))
+            .collect();
+        Self {
+            name: character.name().clone(),
+            basic_damage: BaseDamage::from(*character.attributes()),
+            encumbrance: character.encumbrance_level(),
+            current_move: character.current_move(),
+            dodge: character.dodge() as i64 + db + defense,
+            block,
+            weapons,
+            dr: character.dr_map(),
+        }
+    }
+}
+
+impl std::fmt::Display for CombatProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: Move {} Dodge {}",
+            self.name, self.current_move, self.dodge
+        )?;
+        if let Some(block) = self.block {
+            write!(f, " Block {block}")?;
+        }
+        for weapon in &self.weapons {
+            write!(f, "; {weapon}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{long_distance_modifier, Backfire, Character, Outcome, Random, Skill, SuccessRoll};
+
+/// Seconds each energy gathering roll takes. Thaumatology-97
+pub const CYCLE_SECONDS: usize = 300;
+
+/// Minutes in a year of 365 days, the last row of the duration table.
+const MINUTES_PER_YEAR: usize = 525_600;
+
+/// Realm of existence a ritual works on. Thaumatology-93
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Realm {
+    Body,
+    Chance,
+    Crossroads,
+    Energy,
+    Magic,
+    Matter,
+    Mind,
+    Spirit,
+    Undead,
+}
+
+/// What a ritual does to its realm. Thaumatology-95
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Verb {
+    Sense,
+    Strengthen,
+    Restore,
+    Control,
+    Destroy,
+    Create,
+    Transform,
+}
+
+impl Verb {
+    /// Energy for a lesser effect with the verb.
+    pub fn energy(&self) -> usize {
+        match self {
+            Self::Sense => 2,
+            Self::Strengthen => 3,
+            Self::Restore => 4,
+            Self::Control | Self::Destroy => 5,
+            Self::Create => 6,
+            Self::Transform => 8,
+        }
+    }
+}
+
+/// One verb applied to one realm.  Greater effects can do what lesser ones cannot, at a cost
+/// that multiplies the whole ritual. Thaumatology-95
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Effect {
+    verb: Verb,
+    realm: Realm,
+    greater: bool,
+}
+
+impl std::fmt::Display for Effect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.greater { "Greater" } else { "Lesser" };
+        write!(f, "{kind} {} {}", self.verb, self.realm)
+    }
+}
+
+/// Something that adds to a ritual's energy beyond its effects. Thaumatology-98
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum RitualModifier {
+    /// Minutes the ritual lasts, counting a month as 30 days.
+    #[display("Duration {_0} min")]
+    Duration(usize),
+    /// Meters to a subject out of sight or reach.
+    #[display("Range {_0} m")]
+    Range(usize),
+    /// Energy for any other modifier, such as damage or area.
+    #[display("Extra {_0}")]
+    Extra(usize),
+}
+
+impl RitualModifier {
+    pub fn energy(&self) -> usize {
+        match self {
+            Self::Duration(minutes) => match minutes {
+                0 => 0,
+                1..=10 => 1,
+                11..=30 => 2,
+                31..=60 => 3,
+                61..=180 => 4,
+                181..=360 => 5,
+                361..=720 => 6,
+                721..=1_440 => 7,
+                1_441..=4_320 => 8,
+                4_321..=10_080 => 9,
+                10_081..=20_160 => 10,
+                20_161..=43_200 => 11,
+                43_201..=86_400 => 12,
+                86_401..=129_600 => 13,
+                129_601..=259_200 => 14,
+                // One more for each year or part of a year.
+                _ => 14 + minutes.div_ceil(MINUTES_PER_YEAR),
+            },
+            // Range uses the long-distance modifiers, as a cost instead of a penalty.
+            Self::Range(meters) => long_distance_modifier(*meters).unsigned_abs() as usize,
+            Self::Extra(energy) => *energy,
+        }
+    }
+}
+
+/// A ritual composed of effects and modifiers. Thaumatology-94
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Ritual {
+    effects: Vec<Effect>,
+    modifiers: Vec<RitualModifier>,
+}
+
+impl Ritual {
+    pub fn add_effect(&mut self, effect: Effect) {
+        self.effects.push(effect);
+    }
+
+    pub fn add_modifier(&mut self, modifier: RitualModifier) {
+        self.modifiers.push(modifier);
+    }
+
+    /// Energy to cast: the effects and modifiers added together, then multiplied by one plus
+    /// two for each greater effect. Thaumatology-95
+    pub fn energy(&self) -> usize {
+        let effects = self.effects.iter().map(|e| e.verb.energy()).sum::<usize>();
+        let modifiers = self.modifiers.iter().map(|m| m.energy()).sum::<usize>();
+        let greater = self.effects.iter().filter(|e| e.greater).count();
+        (effects + modifiers) * (1 + 2 * greater)
+    }
+
+    /// Level to gather energy and cast with: Ritual Magic, or Thaumatology if the caster does
+    /// not know it, with any talent or trait bonus.
+    pub fn caster_level(character: &Character) -> Option<i64> {
+        [Skill::RitualMagic, Skill::Thaumatology]
+            .iter()
+            .find(|skill| character.skill_level(skill).is_some())
+            .and_then(|skill| character.effective_skill(skill))
+    }
+
+    /// Begins gathering energy for the ritual.
+    pub fn gather(&self) -> Gathering {
+        Gathering::new(self.energy())
+    }
+}
+
+/// A side effect the ritual picks up from a failed gathering roll, for the GM to describe.
+/// Thaumatology-97
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Quirk {
+    /// Gathering cycle that failed, from one.
+    cycle: usize,
+    /// Margin of the failed roll.
+    margin: i64,
+}
+
+/// Reason a ritual cannot be cast.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+    derive_more::Error,
+)]
+pub enum RitualError {
+    #[display("Only {gathered} of {required} energy has been gathered.")]
+    Incomplete { gathered: usize, required: usize },
+    #[display("The ritual has already backfired.")]
+    Botched,
+}
+
+/// Energy gathered so far toward a ritual, one roll per cycle. Thaumatology-97
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Gathering {
+    required: usize,
+    gathered: usize,
+    cycles: usize,
+    quirks: Vec<Quirk>,
+    /// Set by a critical failure, which ends the ritual.
+    backfire: Option<Backfire>,
+}
+
+impl Gathering {
+    pub fn new(required: usize) -> Self {
+        Self {
+            required,
+            gathered: 0,
+            cycles: 0,
+            quirks: Vec::new(),
+            backfire: None,
+        }
+    }
+
+    /// Rolls one gathering cycle at `level`.  A success gathers one energy plus one per level of
+    /// Magery, tripled on a critical success.  A failure gathers nothing and adds a quirk.  A
+    /// critical failure botches the ritual and loses everything gathered; no further cycles can
+    /// be rolled.
+    pub fn cycle(
+        &mut self,
+        level: i64,
+        magery: usize,
+        random: &mut Random,
+    ) -> Result<SuccessRoll, RitualError> {
+        if self.backfire.is_some() {
+            return Err(RitualError::Botched);
+        }
+        let roll = random.success_roll(level);
+        self.cycles += 1;
+        match roll.outcome() {
+            Outcome::CriticalSuccess => self.gathered += 3 * (1 + magery),
+            Outcome::Success => self.gathered += 1 + magery,
+            Outcome::Failure => {
+                tracing::trace!("Gathering cycle {} picks up a quirk.", self.cycles);
+                self.quirks.push(Quirk::new(self.cycles, *roll.margin()));
+            }
+            Outcome::CriticalFailure => {
+                tracing::trace!("Gathering cycle {} botches the ritual.", self.cycles);
+                self.gathered = 0;
+                self.backfire = Some(Backfire::from_roll(random.roll()));
+            }
+        }
+        Ok(roll)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.gathered >= self.required
+    }
+
+    /// Seconds spent gathering so far.
+    pub fn casting_time(&self) -> usize {
+        self.cycles * CYCLE_SECONDS
+    }
+
+    /// Rolls to cast the ritual once enough energy is gathered.
+    pub fn cast(&self, level: i64, random: &mut Random) -> Result<SuccessRoll, RitualError> {
+        if self.backfire.is_some() {
+            return Err(RitualError::Botched);
+        }
+        if !self.is_complete() {
+            return Err(RitualError::Incomplete {
+                gathered: self.gathered,
+                required: self.required,
+            });
+        }
+        Ok(random.success_roll(level))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{speed_range_modifier, RollKind};
+
+/// A sense to roll against Perception with. BS-358
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Sense {
+    Vision,
+    Hearing,
+    TasteSmell,
+    Touch,
+}
+
+impl Sense {
+    /// Kind of roll acute senses add to.
+    pub fn roll_kind(&self) -> RollKind {
+        match self {
+            Self::Vision => RollKind::Vision,
+            Self::Hearing => RollKind::Hearing,
+            Self::TasteSmell => RollKind::TasteSmell,
+            Self::Touch => RollKind::Touch,
+        }
+    }
+}
+
+/// Circumstances of a Sense roll. BS-358
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct SenseConditions {
+    /// Penalty for poor light, from 0 to -10. BS-394
+    darkness: i64,
+    /// Meters to what is being sensed, converted to yards for the range table.
+    distance: usize,
+    /// Size Modifier of what is being sensed.
+    size: i64,
+    /// Any other modifier the GM applies.
+    other: i64,
+}
+
+impl SenseConditions {
+    /// Modifier to a roll with `sense`.  Darkness, range and size only matter to Vision; range
+    /// uses the Size and Speed/Range table like a ranged attack. BS-550
+    pub fn modifier(&self, sense: &Sense) -> i64 {
+        match sense {
+            Sense::Vision => {
+                self.darkness + speed_range_modifier(self.distance, 0) + self.size + self.other
+            }
+            Sense::Hearing | Sense::TasteSmell | Sense::Touch => self.other,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::SystemTime;
+
+/// Something that happened during play, and when.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct SessionEntry {
+    at: SystemTime,
+    /// Character the entry is about.
+    name: String,
+    event: String,
+}
+
+/// Record of a game session, oldest entry first.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct SessionLog {
+    entries: Vec<SessionEntry>,
+}
+
+impl SessionLog {
+    pub fn record(&mut self, at: SystemTime, name: &str, event: &str) {
+        tracing::info!("{name}: {event}");
+        self.entries
+            .push(SessionEntry::new(at, name.to_string(), event.to_string()));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::AttributeType;
+use crate::{AttributeType, Attributes};
 use std::str::FromStr;
 
 #[allow(renamed_and_removed_lints)]
@@ -1253,6 +1253,35 @@ impl SkillDefault {
     }
 }
 
+/// A [`Skill`] a character has learned, with the character points spent on it.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct KnownSkill {
+    skill: Skill,
+    points: usize,
+}
+
+impl KnownSkill {
+    /// Skill level for a character with the given attributes.
+    pub fn level(&self, attributes: &Attributes) -> Option<i64> {
+        let attribute = attributes.value(&self.skill.attribute()) as i64;
+        let relative = self.skill.difficulty().relative_level(self.points)?;
+        Some(attribute + relative)
+    }
+}
+
 /// Specializations for the Artist skill.
 #[derive(
     Debug,
@@ -1614,6 +1643,38 @@ pub enum Difficulty {
 }
 
 impl Difficulty {
+    /// Skill level relative to the controlling attribute for `points` spent. BS-170
+    /// One point buys attribute-0 for Easy skills, -1 for Average, -2 for Hard and -3 for Very
+    /// Hard.  Two points add +1, four points +2, eight points +3, then +1 per four points more.
+    pub fn relative_level(&self, points: usize) -> Option<i64> {
+        let base = match self {
+            Self::Easy => 0,
+            Self::Average => -1,
+            Self::Hard => -2,
+            Self::VeryHard => -3,
+            Self::Special => return None,
+        };
+        let bought = match points {
+            0 => return None,
+            1 => 0,
+            2..4 => 1,
+            4..8 => 2,
+            _ => 3 + (points as i64 - 8) / 4,
+        };
+        Some(base + bought)
+    }
+
+    /// Modifier to the controlling attribute when using a skill that has not been learned.
+    /// Very Hard skills have no attribute default. BS-173
+    pub fn default_modifier(&self) -> Option<i64> {
+        match self {
+            Self::Easy => Some(-4),
+            Self::Average => Some(-5),
+            Self::Hard => Some(-6),
+            Self::VeryHard | Self::Special => None,
+        }
+    }
+
     /// Parses common abbreviations for `Difficulty` used in the text references.
     pub fn from_abbr(abbr: &str) -> Option<Self> {
         let lwr = abbr.to_lowercase();
@@ -11,6 +11,7 @@ use crate::{Advantage, Disadvantage, Perk};
     Hash,
     serde::Serialize,
     serde::Deserialize,
+    derive_getters::Getters,
     derive_new::new,
 )]
 pub struct SpecialFeatures {
@@ -18,3 +19,9 @@ pub struct SpecialFeatures {
     disadvantages: Vec<Disadvantage>,
     perks: Vec<Perk>,
 }
+
+impl SpecialFeatures {
+    pub fn add_disadvantage(&mut self, disadvantage: Disadvantage) {
+        self.disadvantages.push(disadvantage);
+    }
+}
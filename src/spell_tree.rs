@@ -0,0 +1,277 @@
+use std::collections::BTreeSet;
+
+use strum::IntoEnumIterator;
+
+use crate::{Advantage, AttributeType, Character, College, Prerequisite, Spell};
+
+/// A prerequisite a character is missing for a spell they know or want to learn.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_more::Display,
+    derive_more::Error,
+    derive_new::new,
+)]
+#[display("{spell} requires {prerequisite}.")]
+pub struct PrerequisiteError {
+    spell: Spell,
+    prerequisite: Prerequisite,
+}
+
+/// What a caster brings to the prerequisites of a spell.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Caster {
+    magery: usize,
+    iq: usize,
+    known: BTreeSet<Spell>,
+}
+
+impl Caster {
+    fn from_character(character: &Character) -> Self {
+        Self {
+            magery: character.advantage_level(&Advantage::Magery(0)),
+            iq: character.attributes().value(&AttributeType::Intelligence),
+            known: character.spells().iter().map(|k| *k.spell()).collect(),
+        }
+    }
+
+    fn count(&self, college: &College, except: &Spell) -> usize {
+        self.known
+            .iter()
+            .filter(|s| s.college() == *college && *s != except)
+            .count()
+    }
+
+    fn meets(&self, spell: &Spell, prerequisite: &Prerequisite) -> bool {
+        match prerequisite {
+            Prerequisite::Magery(level) => self.magery >= *level,
+            Prerequisite::Intelligence(iq) => self.iq >= *iq,
+            Prerequisite::Spell(required) => self.known.contains(required),
+            Prerequisite::College(college, count) => self.count(college, spell) >= *count,
+            Prerequisite::Spells(count) => {
+                self.known.iter().filter(|s| *s != spell).count() >= *count
+            }
+        }
+    }
+
+    fn unmet(&self, spell: &Spell) -> Vec<PrerequisiteError> {
+        spell
+            .prerequisites()
+            .into_iter()
+            .filter(|p| !self.meets(spell, p))
+            .map(|p| PrerequisiteError::new(*spell, p))
+            .collect()
+    }
+
+    /// Learns `spell` and everything it needs, appending each spell learned to `path`.  `None`
+    /// if Magery or IQ falls short, or the spell cannot be reached.
+    fn acquire(
+        &mut self,
+        spell: Spell,
+        path: &mut Vec<Spell>,
+        pending: &mut BTreeSet<Spell>,
+    ) -> Option<()> {
+        if self.known.contains(&spell) {
+            return Some(());
+        }
+        if !pending.insert(spell) {
+            return None;
+        }
+        for prerequisite in spell.prerequisites() {
+            match prerequisite {
+                Prerequisite::Magery(_) | Prerequisite::Intelligence(_) => {
+                    if !self.meets(&spell, &prerequisite) {
+                        return None;
+                    }
+                }
+                Prerequisite::Spell(required) => self.acquire(required, path, pending)?,
+                Prerequisite::College(college, _) => {
+                    while !self.meets(&spell, &prerequisite) {
+                        let next = self.cheapest(|s| s.college() == college, &spell, pending)?;
+                        self.acquire(next, path, pending)?;
+                    }
+                }
+                Prerequisite::Spells(_) => {
+                    while !self.meets(&spell, &prerequisite) {
+                        let next = self.cheapest(|_| true, &spell, pending)?;
+                        self.acquire(next, path, pending)?;
+                    }
+                }
+            }
+        }
+        pending.remove(&spell);
+        self.known.insert(spell);
+        path.push(spell);
+        Some(())
+    }
+
+    /// The first requirement of a spell in `planned` not met by everything known or planned.
+    fn first_unmet(&self, planned: &BTreeSet<Spell>) -> Option<(Spell, Prerequisite)> {
+        let mut combined = self.clone();
+        combined.known.extend(planned.iter().copied());
+        planned.iter().find_map(|spell| {
+            spell
+                .prerequisites()
+                .into_iter()
+                .find(|p| !combined.meets(spell, p))
+                .map(|p| (*spell, p))
+        })
+    }
+
+    /// Looks for a smaller set of spells than `best` that, once learned, lets the caster learn
+    /// every spell in `planned`.  Each unmet prerequisite adds the spell it names, or branches on
+    /// every spell that could count toward it.
+    fn search(
+        &self,
+        planned: BTreeSet<Spell>,
+        target: &Spell,
+        best: &mut Vec<Spell>,
+        seen: &mut BTreeSet<BTreeSet<Spell>>,
+    ) {
+        if planned.len() >= best.len() || !seen.insert(planned.clone()) {
+            return;
+        }
+        match self.first_unmet(&planned) {
+            None => {
+                if let Some(path) = self.order(&planned, target) {
+                    *best = path;
+                }
+            }
+            Some((_, Prerequisite::Magery(_) | Prerequisite::Intelligence(_))) => {}
+            Some((_, Prerequisite::Spell(required))) => {
+                let mut next = planned;
+                next.insert(required);
+                self.search(next, target, best, seen);
+            }
+            Some((spell, prerequisite)) => {
+                let (college, count) = match prerequisite {
+                    Prerequisite::College(college, count) => (Some(college), count),
+                    Prerequisite::Spells(count) => (None, count),
+                    _ => return,
+                };
+                let have = self
+                    .known
+                    .iter()
+                    .chain(planned.iter())
+                    .filter(|s| **s != spell && college.is_none_or(|c| s.college() == c))
+                    .count();
+                if planned.len() + count.saturating_sub(have) >= best.len() {
+                    return;
+                }
+                let candidates = Spell::iter().filter(|s| {
+                    college.is_none_or(|c| s.college() == c)
+                        && !self.known.contains(s)
+                        && !planned.contains(s)
+                });
+                for candidate in candidates {
+                    let mut next = planned.clone();
+                    next.insert(candidate);
+                    self.search(next, target, best, seen);
+                }
+            }
+        }
+    }
+
+    /// An order to learn `planned` in, each spell after its prerequisites and `target` last.
+    /// `None` if the spells depend on each other in a circle.
+    fn order(&self, planned: &BTreeSet<Spell>, target: &Spell) -> Option<Vec<Spell>> {
+        let mut caster = self.clone();
+        let mut rest = planned.clone();
+        let mut path = Vec::new();
+        while !rest.is_empty() {
+            let next = rest
+                .iter()
+                .copied()
+                .filter(|s| s != target || rest.len() == 1)
+                .find(|s| caster.unmet(s).is_empty())?;
+            rest.remove(&next);
+            caster.known.insert(next);
+            path.push(next);
+        }
+        Some(path)
+    }
+
+    /// The unknown spell matching `filter`, other than `target`, that takes the fewest spells
+    /// to learn.
+    fn cheapest(
+        &self,
+        filter: impl Fn(&Spell) -> bool,
+        target: &Spell,
+        pending: &BTreeSet<Spell>,
+    ) -> Option<Spell> {
+        Spell::iter()
+            .filter(|s| filter(s) && s != target && !self.known.contains(s))
+            .filter_map(|candidate| {
+                let mut caster = self.clone();
+                let mut path = Vec::new();
+                caster.acquire(candidate, &mut path, &mut pending.clone())?;
+                Some((path.len(), candidate))
+            })
+            .min()
+            .map(|(_, spell)| spell)
+    }
+}
+
+/// The graph of spell prerequisites, checked against what a character knows. BS-235
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SpellTree;
+
+impl SpellTree {
+    /// Every prerequisite missing from the spells the character knows.
+    pub fn validate(character: &Character) -> Result<(), Vec<PrerequisiteError>> {
+        let caster = Caster::from_character(character);
+        let errors = caster
+            .known
+            .iter()
+            .flat_map(|spell| caster.unmet(spell))
+            .collect::<Vec<PrerequisiteError>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Prerequisites the character is missing to learn `spell`.
+    pub fn unmet(character: &Character, spell: &Spell) -> Vec<PrerequisiteError> {
+        Caster::from_character(character).unmet(spell)
+    }
+
+    /// Spells the character does not know but meets every prerequisite for.
+    pub fn eligible(character: &Character) -> Vec<Spell> {
+        let caster = Caster::from_character(character);
+        Spell::iter()
+            .filter(|s| !caster.known.contains(s) && caster.unmet(s).is_empty())
+            .collect()
+    }
+
+    /// The fewest spells the character must learn, in order, to learn `target`, ending with
+    /// `target` itself, or empty if the character already knows `target`.  `None` if the
+    /// character lacks the Magery or IQ for a spell on the way.
+    /// A path picked greedily, one cheapest spell at a time, bounds a search for a shorter one.
+    pub fn path_to(character: &Character, target: Spell) -> Option<Vec<Spell>> {
+        let caster = Caster::from_character(character);
+        let mut best = Vec::new();
+        caster
+            .clone()
+            .acquire(target, &mut best, &mut BTreeSet::new())?;
+        if !best.is_empty() {
+            caster.search(
+                BTreeSet::from([target]),
+                &target,
+                &mut best,
+                &mut BTreeSet::new(),
+            );
+        }
+        Some(best)
+    }
+}
@@ -0,0 +1,731 @@
+use crate::{AttributeType, Attributes, Difficulty};
+
+/// Schools of magic that group related spells. BS-242
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum College {
+    Air,
+    BodyControl,
+    Communication,
+    Fire,
+    Healing,
+    Knowledge,
+    LightAndDarkness,
+    Meta,
+    MindControl,
+    Movement,
+    Protection,
+}
+
+/// How a spell is cast and aimed. BS-239
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum SpellClass {
+    /// Affects one subject, at -1 per meter of distance.
+    Regular,
+    /// Affects everything in an area, with no distance penalty.
+    Area,
+    /// Charged over one or more seconds and thrown as a ranged attack.
+    Missile,
+    /// Gathers information, at a long-distance penalty.
+    Information,
+}
+
+/// How long a spell lasts once cast. BS-236
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum SpellDuration {
+    #[display("Instant")]
+    Instant,
+    #[display("{_0} sec")]
+    Seconds(usize),
+    #[display("{_0} min")]
+    Minutes(usize),
+    #[display("{_0} hr")]
+    Hours(usize),
+    #[display("Permanent")]
+    Permanent,
+}
+
+/// Energy to cast a spell and to maintain it for another full duration. BS-236
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Energy {
+    /// Base cost to cast.  Area spells pay this per meter of radius, Missile spells per second
+    /// spent charging.
+    cast: usize,
+    /// `None` for spells that cannot be maintained.
+    maintain: Option<usize>,
+}
+
+impl Energy {
+    /// Energy after the reduction for a caster at skill `level`.
+    pub fn at(&self, level: i64) -> Self {
+        let reduction = cost_reduction(level);
+        Self::new(
+            self.cast.saturating_sub(reduction),
+            self.maintain.map(|m| m.saturating_sub(reduction)),
+        )
+    }
+}
+
+/// Reduction to the energy to cast or maintain a spell for a caster with high skill: 1 at
+/// skill 15-19, 2 at 20-24, and 1 more for every further 5 levels. BS-237
+pub fn cost_reduction(level: i64) -> usize {
+    if level < 15 {
+        0
+    } else {
+        1 + (level as usize - 15) / 5
+    }
+}
+
+/// Something a caster must have before learning a spell. BS-235
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum Prerequisite {
+    #[display("Magery {_0}")]
+    Magery(usize),
+    #[display("IQ {_0}+")]
+    Intelligence(usize),
+    Spell(Spell),
+    /// At least this many spells from the college.
+    #[display("{_1} {_0} spells")]
+    College(College, usize),
+    /// At least this many spells of any college besides the spell itself, including any spells
+    /// it requires by name.
+    #[display("{_0} other spells")]
+    Spells(usize),
+}
+
+/// Spells from the Basic Set, each learned as a separate IQ-based skill. BS-242
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Spell {
+    PurifyAir,
+    CreateAir,
+    ShapeAir,
+    NoSmell,
+    Stench,
+    WalkOnAir,
+    Lightning,
+    Itch,
+    Spasm,
+    Pain,
+    Clumsiness,
+    SenseFoes,
+    SenseEmotion,
+    IgniteFire,
+    CreateFire,
+    ShapeFire,
+    ExtinguishFire,
+    Heat,
+    Cold,
+    Fireball,
+    ExplosiveFireball,
+    LendEnergy,
+    LendVitality,
+    RecoverEnergy,
+    MinorHealing,
+    MajorHealing,
+    Awaken,
+    DetectMagic,
+    Aura,
+    Light,
+    ContinualLight,
+    Darkness,
+    Blur,
+    Counterspell,
+    DispelMagic,
+    Foolishness,
+    Daze,
+    Sleep,
+    Fear,
+    Haste,
+    Apportation,
+    Levitation,
+    Shield,
+    Armor,
+}
+
+impl Spell {
+    /// The [`College`] the spell belongs to.
+    pub fn college(&self) -> College {
+        match self {
+            Self::PurifyAir => College::Air,
+            Self::CreateAir => College::Air,
+            Self::ShapeAir => College::Air,
+            Self::NoSmell => College::Air,
+            Self::Stench => College::Air,
+            Self::WalkOnAir => College::Air,
+            Self::Lightning => College::Air,
+            Self::Itch => College::BodyControl,
+            Self::Spasm => College::BodyControl,
+            Self::Pain => College::BodyControl,
+            Self::Clumsiness => College::BodyControl,
+            Self::SenseFoes => College::Communication,
+            Self::SenseEmotion => College::Communication,
+            Self::IgniteFire => College::Fire,
+            Self::CreateFire => College::Fire,
+            Self::ShapeFire => College::Fire,
+            Self::ExtinguishFire => College::Fire,
+            Self::Heat => College::Fire,
+            Self::Cold => College::Fire,
+            Self::Fireball => College::Fire,
+            Self::ExplosiveFireball => College::Fire,
+            Self::LendEnergy => College::Healing,
+            Self::LendVitality => College::Healing,
+            Self::RecoverEnergy => College::Healing,
+            Self::MinorHealing => College::Healing,
+            Self::MajorHealing => College::Healing,
+            Self::Awaken => College::Healing,
+            Self::DetectMagic => College::Knowledge,
+            Self::Aura => College::Knowledge,
+            Self::Light => College::LightAndDarkness,
+            Self::ContinualLight => College::LightAndDarkness,
+            Self::Darkness => College::LightAndDarkness,
+            Self::Blur => College::LightAndDarkness,
+            Self::Counterspell => College::Meta,
+            Self::DispelMagic => College::Meta,
+            Self::Foolishness => College::MindControl,
+            Self::Daze => College::MindControl,
+            Self::Sleep => College::MindControl,
+            Self::Fear => College::MindControl,
+            Self::Haste => College::Movement,
+            Self::Apportation => College::Movement,
+            Self::Levitation => College::Movement,
+            Self::Shield => College::Protection,
+            Self::Armor => College::Protection,
+        }
+    }
+
+    /// What the caster needs before learning the spell.
+    pub fn prerequisites(&self) -> Vec<Prerequisite> {
+        match self {
+            Self::PurifyAir => Vec::new(),
+            Self::CreateAir => vec![Prerequisite::Spell(Self::PurifyAir)],
+            Self::ShapeAir => vec![Prerequisite::Spell(Self::CreateAir)],
+            Self::NoSmell => vec![Prerequisite::Spell(Self::PurifyAir)],
+            Self::Stench => vec![Prerequisite::Spell(Self::PurifyAir)],
+            Self::WalkOnAir => vec![Prerequisite::Spell(Self::ShapeAir)],
+            Self::Lightning => vec![
+                Prerequisite::Magery(1),
+                Prerequisite::College(College::Air, 6),
+            ],
+            Self::Itch => Vec::new(),
+            Self::Spasm => vec![Prerequisite::Spell(Self::Itch)],
+            Self::Pain => vec![Prerequisite::Spell(Self::Spasm)],
+            Self::Clumsiness => vec![Prerequisite::Spell(Self::Spasm)],
+            Self::SenseFoes => Vec::new(),
+            Self::SenseEmotion => vec![Prerequisite::Spell(Self::SenseFoes)],
+            Self::IgniteFire => Vec::new(),
+            Self::CreateFire => vec![Prerequisite::Spell(Self::IgniteFire)],
+            Self::ShapeFire => vec![Prerequisite::Spell(Self::IgniteFire)],
+            Self::ExtinguishFire => vec![Prerequisite::Spell(Self::IgniteFire)],
+            Self::Heat => vec![
+                Prerequisite::Spell(Self::CreateFire),
+                Prerequisite::Spell(Self::ShapeFire),
+            ],
+            Self::Cold => vec![Prerequisite::Spell(Self::Heat)],
+            Self::Fireball => vec![
+                Prerequisite::Magery(1),
+                Prerequisite::Spell(Self::CreateFire),
+                Prerequisite::Spell(Self::ShapeFire),
+            ],
+            Self::ExplosiveFireball => vec![Prerequisite::Spell(Self::Fireball)],
+            Self::LendEnergy => vec![Prerequisite::Magery(1)],
+            Self::LendVitality => vec![Prerequisite::Spell(Self::LendEnergy)],
+            Self::RecoverEnergy => vec![
+                Prerequisite::Magery(1),
+                Prerequisite::Spell(Self::LendEnergy),
+            ],
+            Self::MinorHealing => vec![Prerequisite::Spell(Self::LendVitality)],
+            Self::MajorHealing => vec![
+                Prerequisite::Magery(1),
+                Prerequisite::Spell(Self::MinorHealing),
+            ],
+            Self::Awaken => vec![Prerequisite::Spell(Self::LendVitality)],
+            Self::DetectMagic => vec![Prerequisite::Magery(1)],
+            Self::Aura => vec![Prerequisite::Spell(Self::DetectMagic)],
+            Self::Light => Vec::new(),
+            Self::ContinualLight => vec![Prerequisite::Spell(Self::Light)],
+            Self::Darkness => vec![Prerequisite::Spell(Self::ContinualLight)],
+            Self::Blur => vec![Prerequisite::Spell(Self::Darkness)],
+            Self::Counterspell => vec![Prerequisite::Magery(1)],
+            // Counterspell and 12 other spells.
+            Self::DispelMagic => vec![
+                Prerequisite::Spell(Self::Counterspell),
+                Prerequisite::Spells(13),
+            ],
+            Self::Foolishness => vec![Prerequisite::Intelligence(12)],
+            Self::Daze => vec![Prerequisite::Spell(Self::Foolishness)],
+            Self::Sleep => vec![Prerequisite::Spell(Self::Daze)],
+            Self::Fear => vec![Prerequisite::Spell(Self::SenseEmotion)],
+            Self::Haste => Vec::new(),
+            Self::Apportation => vec![Prerequisite::Magery(1)],
+            Self::Levitation => vec![Prerequisite::Spell(Self::Apportation)],
+            Self::Shield => vec![Prerequisite::Magery(2)],
+            Self::Armor => vec![Prerequisite::Spell(Self::Shield)],
+        }
+    }
+
+    /// The [`Difficulty`] of the spell, always Hard or Very Hard.
+    pub fn difficulty(&self) -> Difficulty {
+        match self {
+            Self::PurifyAir => Difficulty::Hard,
+            Self::CreateAir => Difficulty::Hard,
+            Self::ShapeAir => Difficulty::Hard,
+            Self::NoSmell => Difficulty::Hard,
+            Self::Stench => Difficulty::Hard,
+            Self::WalkOnAir => Difficulty::Hard,
+            Self::Lightning => Difficulty::Hard,
+            Self::Itch => Difficulty::Hard,
+            Self::Spasm => Difficulty::Hard,
+            Self::Pain => Difficulty::Hard,
+            Self::Clumsiness => Difficulty::Hard,
+            Self::SenseFoes => Difficulty::Hard,
+            Self::SenseEmotion => Difficulty::Hard,
+            Self::IgniteFire => Difficulty::Hard,
+            Self::CreateFire => Difficulty::Hard,
+            Self::ShapeFire => Difficulty::Hard,
+            Self::ExtinguishFire => Difficulty::Hard,
+            Self::Heat => Difficulty::Hard,
+            Self::Cold => Difficulty::Hard,
+            Self::Fireball => Difficulty::Hard,
+            Self::ExplosiveFireball => Difficulty::Hard,
+            Self::LendEnergy => Difficulty::Hard,
+            Self::LendVitality => Difficulty::Hard,
+            Self::RecoverEnergy => Difficulty::Hard,
+            Self::MinorHealing => Difficulty::Hard,
+            Self::MajorHealing => Difficulty::VeryHard,
+            Self::Awaken => Difficulty::Hard,
+            Self::DetectMagic => Difficulty::Hard,
+            Self::Aura => Difficulty::Hard,
+            Self::Light => Difficulty::Hard,
+            Self::ContinualLight => Difficulty::Hard,
+            Self::Darkness => Difficulty::Hard,
+            Self::Blur => Difficulty::Hard,
+            Self::Counterspell => Difficulty::Hard,
+            Self::DispelMagic => Difficulty::Hard,
+            Self::Foolishness => Difficulty::Hard,
+            Self::Daze => Difficulty::Hard,
+            Self::Sleep => Difficulty::Hard,
+            Self::Fear => Difficulty::Hard,
+            Self::Haste => Difficulty::Hard,
+            Self::Apportation => Difficulty::Hard,
+            Self::Levitation => Difficulty::Hard,
+            Self::Shield => Difficulty::Hard,
+            Self::Armor => Difficulty::Hard,
+        }
+    }
+
+    /// How the spell is cast and aimed.
+    pub fn class(&self) -> SpellClass {
+        match self {
+            Self::PurifyAir => SpellClass::Area,
+            Self::CreateAir => SpellClass::Area,
+            Self::ShapeAir => SpellClass::Regular,
+            Self::NoSmell => SpellClass::Regular,
+            Self::Stench => SpellClass::Area,
+            Self::WalkOnAir => SpellClass::Regular,
+            Self::Lightning => SpellClass::Missile,
+            Self::Itch => SpellClass::Regular,
+            Self::Spasm => SpellClass::Regular,
+            Self::Pain => SpellClass::Regular,
+            Self::Clumsiness => SpellClass::Regular,
+            Self::SenseFoes => SpellClass::Information,
+            Self::SenseEmotion => SpellClass::Regular,
+            Self::IgniteFire => SpellClass::Regular,
+            Self::CreateFire => SpellClass::Area,
+            Self::ShapeFire => SpellClass::Area,
+            Self::ExtinguishFire => SpellClass::Area,
+            Self::Heat => SpellClass::Regular,
+            Self::Cold => SpellClass::Regular,
+            Self::Fireball => SpellClass::Missile,
+            Self::ExplosiveFireball => SpellClass::Missile,
+            Self::LendEnergy => SpellClass::Regular,
+            Self::LendVitality => SpellClass::Regular,
+            Self::RecoverEnergy => SpellClass::Regular,
+            Self::MinorHealing => SpellClass::Regular,
+            Self::MajorHealing => SpellClass::Regular,
+            Self::Awaken => SpellClass::Area,
+            Self::DetectMagic => SpellClass::Regular,
+            Self::Aura => SpellClass::Information,
+            Self::Light => SpellClass::Regular,
+            Self::ContinualLight => SpellClass::Regular,
+            Self::Darkness => SpellClass::Area,
+            Self::Blur => SpellClass::Regular,
+            Self::Counterspell => SpellClass::Regular,
+            Self::DispelMagic => SpellClass::Area,
+            Self::Foolishness => SpellClass::Regular,
+            Self::Daze => SpellClass::Regular,
+            Self::Sleep => SpellClass::Regular,
+            Self::Fear => SpellClass::Area,
+            Self::Haste => SpellClass::Regular,
+            Self::Apportation => SpellClass::Regular,
+            Self::Levitation => SpellClass::Regular,
+            Self::Shield => SpellClass::Regular,
+            Self::Armor => SpellClass::Regular,
+        }
+    }
+
+    /// Seconds of concentration to cast the spell.
+    pub fn casting_time(&self) -> usize {
+        match self {
+            Self::PurifyAir => 1,
+            Self::CreateAir => 1,
+            Self::ShapeAir => 1,
+            Self::NoSmell => 1,
+            Self::Stench => 1,
+            Self::WalkOnAir => 1,
+            Self::Lightning => 1,
+            Self::Itch => 1,
+            Self::Spasm => 1,
+            Self::Pain => 1,
+            Self::Clumsiness => 1,
+            Self::SenseFoes => 1,
+            Self::SenseEmotion => 1,
+            Self::IgniteFire => 1,
+            Self::CreateFire => 1,
+            Self::ShapeFire => 2,
+            Self::ExtinguishFire => 1,
+            Self::Heat => 60,
+            Self::Cold => 60,
+            Self::Fireball => 1,
+            Self::ExplosiveFireball => 1,
+            Self::LendEnergy => 1,
+            Self::LendVitality => 1,
+            Self::RecoverEnergy => 0,
+            Self::MinorHealing => 1,
+            Self::MajorHealing => 1,
+            Self::Awaken => 1,
+            Self::DetectMagic => 5,
+            Self::Aura => 1,
+            Self::Light => 1,
+            Self::ContinualLight => 1,
+            Self::Darkness => 1,
+            Self::Blur => 2,
+            Self::Counterspell => 5,
+            Self::DispelMagic => 1,
+            Self::Foolishness => 1,
+            Self::Daze => 2,
+            Self::Sleep => 3,
+            Self::Fear => 1,
+            Self::Haste => 2,
+            Self::Apportation => 1,
+            Self::Levitation => 2,
+            Self::Shield => 1,
+            Self::Armor => 1,
+        }
+    }
+
+    /// Base energy to cast and maintain the spell.
+    pub fn energy(&self) -> Energy {
+        match self {
+            Self::PurifyAir => Energy::new(1, None),
+            Self::CreateAir => Energy::new(1, None),
+            Self::ShapeAir => Energy::new(1, Some(1)),
+            Self::NoSmell => Energy::new(2, Some(2)),
+            Self::Stench => Energy::new(1, None),
+            Self::WalkOnAir => Energy::new(3, Some(2)),
+            Self::Lightning => Energy::new(1, None),
+            Self::Itch => Energy::new(2, None),
+            Self::Spasm => Energy::new(2, None),
+            Self::Pain => Energy::new(2, None),
+            Self::Clumsiness => Energy::new(1, Some(1)),
+            Self::SenseFoes => Energy::new(1, None),
+            Self::SenseEmotion => Energy::new(2, None),
+            Self::IgniteFire => Energy::new(1, None),
+            Self::CreateFire => Energy::new(2, Some(1)),
+            Self::ShapeFire => Energy::new(2, Some(1)),
+            Self::ExtinguishFire => Energy::new(3, None),
+            Self::Heat => Energy::new(1, Some(1)),
+            Self::Cold => Energy::new(1, Some(1)),
+            Self::Fireball => Energy::new(1, None),
+            Self::ExplosiveFireball => Energy::new(2, None),
+            Self::LendEnergy => Energy::new(1, None),
+            Self::LendVitality => Energy::new(1, None),
+            Self::RecoverEnergy => Energy::new(0, None),
+            Self::MinorHealing => Energy::new(1, None),
+            Self::MajorHealing => Energy::new(1, None),
+            Self::Awaken => Energy::new(1, None),
+            Self::DetectMagic => Energy::new(2, None),
+            Self::Aura => Energy::new(3, None),
+            Self::Light => Energy::new(1, Some(1)),
+            Self::ContinualLight => Energy::new(2, None),
+            Self::Darkness => Energy::new(2, Some(1)),
+            Self::Blur => Energy::new(1, Some(1)),
+            Self::Counterspell => Energy::new(1, None),
+            Self::DispelMagic => Energy::new(3, None),
+            Self::Foolishness => Energy::new(1, Some(1)),
+            Self::Daze => Energy::new(3, Some(2)),
+            Self::Sleep => Energy::new(4, None),
+            Self::Fear => Energy::new(1, None),
+            Self::Haste => Energy::new(2, Some(1)),
+            Self::Apportation => Energy::new(1, Some(1)),
+            Self::Levitation => Energy::new(1, Some(1)),
+            Self::Shield => Energy::new(2, Some(2)),
+            Self::Armor => Energy::new(2, Some(2)),
+        }
+    }
+
+    /// How long the spell lasts.
+    pub fn duration(&self) -> SpellDuration {
+        match self {
+            Self::PurifyAir => SpellDuration::Instant,
+            Self::CreateAir => SpellDuration::Seconds(5),
+            Self::ShapeAir => SpellDuration::Minutes(1),
+            Self::NoSmell => SpellDuration::Hours(1),
+            Self::Stench => SpellDuration::Minutes(5),
+            Self::WalkOnAir => SpellDuration::Minutes(1),
+            Self::Lightning => SpellDuration::Instant,
+            Self::Itch => SpellDuration::Instant,
+            Self::Spasm => SpellDuration::Instant,
+            Self::Pain => SpellDuration::Seconds(1),
+            Self::Clumsiness => SpellDuration::Minutes(1),
+            Self::SenseFoes => SpellDuration::Instant,
+            Self::SenseEmotion => SpellDuration::Instant,
+            Self::IgniteFire => SpellDuration::Seconds(1),
+            Self::CreateFire => SpellDuration::Minutes(1),
+            Self::ShapeFire => SpellDuration::Minutes(1),
+            Self::ExtinguishFire => SpellDuration::Instant,
+            Self::Heat => SpellDuration::Minutes(1),
+            Self::Cold => SpellDuration::Minutes(1),
+            Self::Fireball => SpellDuration::Instant,
+            Self::ExplosiveFireball => SpellDuration::Instant,
+            Self::LendEnergy => SpellDuration::Instant,
+            Self::LendVitality => SpellDuration::Hours(1),
+            Self::RecoverEnergy => SpellDuration::Permanent,
+            Self::MinorHealing => SpellDuration::Permanent,
+            Self::MajorHealing => SpellDuration::Permanent,
+            Self::Awaken => SpellDuration::Instant,
+            Self::DetectMagic => SpellDuration::Instant,
+            Self::Aura => SpellDuration::Instant,
+            Self::Light => SpellDuration::Minutes(1),
+            Self::ContinualLight => SpellDuration::Permanent,
+            Self::Darkness => SpellDuration::Minutes(1),
+            Self::Blur => SpellDuration::Minutes(1),
+            Self::Counterspell => SpellDuration::Instant,
+            Self::DispelMagic => SpellDuration::Instant,
+            Self::Foolishness => SpellDuration::Minutes(1),
+            Self::Daze => SpellDuration::Minutes(1),
+            Self::Sleep => SpellDuration::Instant,
+            Self::Fear => SpellDuration::Minutes(10),
+            Self::Haste => SpellDuration::Minutes(1),
+            Self::Apportation => SpellDuration::Minutes(1),
+            Self::Levitation => SpellDuration::Minutes(1),
+            Self::Shield => SpellDuration::Minutes(1),
+            Self::Armor => SpellDuration::Minutes(1),
+        }
+    }
+
+    /// Attribute an unwilling subject resists with, if the spell can be resisted.
+    pub fn resistance(&self) -> Option<AttributeType> {
+        match self {
+            Self::PurifyAir => None,
+            Self::CreateAir => None,
+            Self::ShapeAir => None,
+            Self::NoSmell => None,
+            Self::Stench => None,
+            Self::WalkOnAir => None,
+            Self::Lightning => None,
+            Self::Itch => Some(AttributeType::Health),
+            Self::Spasm => Some(AttributeType::Health),
+            Self::Pain => Some(AttributeType::Health),
+            Self::Clumsiness => Some(AttributeType::Health),
+            Self::SenseFoes => None,
+            Self::SenseEmotion => None,
+            Self::IgniteFire => None,
+            Self::CreateFire => None,
+            Self::ShapeFire => None,
+            Self::ExtinguishFire => None,
+            Self::Heat => None,
+            Self::Cold => None,
+            Self::Fireball => None,
+            Self::ExplosiveFireball => None,
+            Self::LendEnergy => None,
+            Self::LendVitality => None,
+            Self::RecoverEnergy => None,
+            Self::MinorHealing => None,
+            Self::MajorHealing => None,
+            Self::Awaken => None,
+            Self::DetectMagic => None,
+            Self::Aura => None,
+            Self::Light => None,
+            Self::ContinualLight => None,
+            Self::Darkness => None,
+            Self::Blur => None,
+            Self::Counterspell => None,
+            Self::DispelMagic => None,
+            Self::Foolishness => Some(AttributeType::Willpower),
+            Self::Daze => Some(AttributeType::Health),
+            Self::Sleep => Some(AttributeType::Health),
+            Self::Fear => Some(AttributeType::Willpower),
+            Self::Haste => None,
+            Self::Apportation => Some(AttributeType::Willpower),
+            Self::Levitation => Some(AttributeType::Strength),
+            Self::Shield => None,
+            Self::Armor => None,
+        }
+    }
+
+    /// Book and page reference for the `Spell` description.
+    pub fn reference(&self) -> &'static str {
+        match self {
+            Self::PurifyAir => "B243",
+            Self::CreateAir => "B243",
+            Self::ShapeAir => "B243",
+            Self::NoSmell => "M24",
+            Self::Stench => "M24",
+            Self::WalkOnAir => "M27",
+            Self::Lightning => "B244",
+            Self::Itch => "B244",
+            Self::Spasm => "B244",
+            Self::Pain => "B244",
+            Self::Clumsiness => "B244",
+            Self::SenseFoes => "B245",
+            Self::SenseEmotion => "B245",
+            Self::IgniteFire => "B246",
+            Self::CreateFire => "B246",
+            Self::ShapeFire => "B246",
+            Self::ExtinguishFire => "B247",
+            Self::Heat => "B247",
+            Self::Cold => "B247",
+            Self::Fireball => "B247",
+            Self::ExplosiveFireball => "B247",
+            Self::LendEnergy => "B248",
+            Self::LendVitality => "B248",
+            Self::RecoverEnergy => "B248",
+            Self::MinorHealing => "B248",
+            Self::MajorHealing => "B248",
+            Self::Awaken => "B248",
+            Self::DetectMagic => "B249",
+            Self::Aura => "B249",
+            Self::Light => "B249",
+            Self::ContinualLight => "B249",
+            Self::Darkness => "B250",
+            Self::Blur => "B250",
+            Self::Counterspell => "B250",
+            Self::DispelMagic => "B250",
+            Self::Foolishness => "B251",
+            Self::Daze => "B250",
+            Self::Sleep => "B251",
+            Self::Fear => "B250",
+            Self::Haste => "B251",
+            Self::Apportation => "B251",
+            Self::Levitation => "B252",
+            Self::Shield => "B252",
+            Self::Armor => "B253",
+        }
+    }
+
+    /// Whether an unwilling subject may resist the spell.
+    pub fn is_resisted(&self) -> bool {
+        self.resistance().is_some()
+    }
+}
+
+/// A [`Spell`] a character has learned, with the character points spent on it.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct KnownSpell {
+    spell: Spell,
+    points: usize,
+}
+
+impl KnownSpell {
+    /// Spell level for a caster with the given attributes and Magery, which adds its level to
+    /// every spell. BS-235
+    pub fn level(&self, attributes: &Attributes, magery: usize) -> Option<i64> {
+        let iq = attributes.value(&AttributeType::Intelligence) as i64;
+        let relative = self.spell.difficulty().relative_level(self.points)?;
+        Some(iq + relative + magery as i64)
+    }
+
+    /// Energy to cast and maintain the spell at the given level.
+    pub fn energy(&self, level: i64) -> Energy {
+        self.spell.energy().at(level)
+    }
+}
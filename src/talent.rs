@@ -0,0 +1,81 @@
+use crate::skills::Artist;
+use crate::{Advantage, Character, Skill, BARDIC_SKILLS};
+
+/// A leveled advantage that adds its level to a group of skills, including their defaults.
+/// BS-89
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum Talent {
+    /// Taken without levels, so it counts as one.
+    AnimalEmpathy,
+    BardicTalent,
+    HighManualDexterity,
+    MusicalAbility,
+}
+
+impl Talent {
+    /// Most levels a talent can add. BS-89
+    pub const MAX_LEVEL: usize = 4;
+
+    /// The advantage the talent is bought as.
+    pub fn advantage(&self) -> Advantage {
+        match self {
+            Self::AnimalEmpathy => Advantage::AnimalEmpathy,
+            Self::BardicTalent => Advantage::BardicTalent(0),
+            Self::HighManualDexterity => Advantage::HighManualDexterity(0),
+            Self::MusicalAbility => Advantage::MusicalAbility(0),
+        }
+    }
+
+    /// Whether the talent adds to `skill`. BS-59, BS-90
+    pub fn covers(&self, skill: &Skill) -> bool {
+        match self {
+            Self::AnimalEmpathy => matches!(
+                skill,
+                Skill::AnimalHandling | Skill::Mimicry(_) | Skill::Riding | Skill::Veterinary
+            ),
+            Self::BardicTalent => BARDIC_SKILLS.contains(skill),
+            Self::HighManualDexterity => matches!(
+                skill,
+                Skill::Artist(Artist::Sculpting | Artist::Woodworking)
+                    | Skill::Jeweler
+                    | Skill::KnotTying
+                    | Skill::Leatherworking
+                    | Skill::Lockpicking
+                    | Skill::Pickpocket
+                    | Skill::Sewing
+                    | Skill::SleightOfHand
+                    | Skill::Surgery
+            ),
+            Self::MusicalAbility => matches!(
+                skill,
+                Skill::GroupPerformance
+                    | Skill::MusicalComposition
+                    | Skill::MusicalInfluence
+                    | Skill::MusicalInstrument
+                    | Skill::Singing
+            ),
+        }
+    }
+
+    /// The character's level in the talent, no more than `MAX_LEVEL`.
+    pub fn level(&self, character: &Character) -> usize {
+        let level = match self {
+            Self::AnimalEmpathy => character.has(&self.advantage()) as usize,
+            _ => character.advantage_level(&self.advantage()),
+        };
+        level.min(Self::MAX_LEVEL)
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{BaseDamage, DieLevel, Reach, Skill};
+
+/// Low-tech weapons and shields from the Basic Set weapon tables. BS-271
+const CATALOG: &str = include_str!("../data/weapons.json");
+
+/// Type of injury a weapon inflicts. BS-268
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumIter,
+    derive_more::Display,
+)]
+pub enum DamageType {
+    #[display("burn")]
+    Burning,
+    #[display("cr")]
+    Crushing,
+    #[display("cut")]
+    Cutting,
+    #[display("imp")]
+    Impaling,
+    #[display("pi-")]
+    SmallPiercing,
+    #[display("pi")]
+    Piercing,
+    #[display("pi+")]
+    LargePiercing,
+    #[display("pi++")]
+    HugePiercing,
+}
+
+impl DamageType {
+    /// Multiplier applied to damage that penetrates DR, before hit location. BS-379
+    pub fn wounding_modifier(&self) -> f64 {
+        match self {
+            Self::Burning | Self::Crushing | Self::Piercing => 1.0,
+            Self::SmallPiercing => 0.5,
+            Self::Cutting | Self::LargePiercing => 1.5,
+            Self::Impaling | Self::HugePiercing => 2.0,
+        }
+    }
+}
+
+/// Damage of a weapon, either based on the wielder's ST or fixed. BS-269
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_more::Display,
+)]
+pub enum WeaponDamage {
+    /// Thrust damage plus a modifier.
+    #[display("thr{_0:+}")]
+    Thrust(i64),
+    /// Swing damage plus a modifier.
+    #[display("sw{_0:+}")]
+    Swing(i64),
+    Fixed(DieLevel),
+}
+
+impl WeaponDamage {
+    /// Damage for a wielder with the given basic damage.
+    pub fn effective(&self, base: &BaseDamage) -> DieLevel {
+        match self {
+            Self::Thrust(modifier) => base.thrust().die_level().with_pips(*modifier),
+            Self::Swing(modifier) => base.swing().die_level().with_pips(*modifier),
+            Self::Fixed(level) => *level,
+        }
+    }
+}
+
+/// One way of attacking with a weapon, such as a swing or a thrust.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct WeaponAttack {
+    damage: WeaponDamage,
+    damage_type: DamageType,
+    /// Melee reach, `None` for ranged attacks.
+    reach: Option<Reach>,
+}
+
+impl WeaponAttack {
+    pub fn damage_for(&self, base: &BaseDamage) -> DieLevel {
+        self.damage.effective(base)
+    }
+}
+
+/// Parry with a melee weapon. BS-269
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Parry {
+    modifier: i64,
+    /// Unbalanced weapons cannot parry if they attacked this turn ("U").
+    unbalanced: bool,
+    /// Fencing weapons retreat and parry repeatedly with less penalty ("F").
+    fencing: bool,
+}
+
+/// Range of a ranged weapon, in meters. BS-269
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+pub enum Range {
+    /// Muscle-powered weapons, given as multiples of the wielder's ST.
+    Strength {
+        half: f64,
+        max: f64,
+    },
+    Fixed {
+        half: usize,
+        max: usize,
+    },
+}
+
+impl Range {
+    /// Half-damage range and maximum range for a wielder with `st`.
+    pub fn meters(&self, st: usize) -> (usize, usize) {
+        match self {
+            Self::Strength { half, max } => {
+                let st = st as f64;
+                ((st * half).floor() as usize, (st * max).floor() as usize)
+            }
+            Self::Fixed { half, max } => (*half, *max),
+        }
+    }
+}
+
+/// Statistics for a ranged weapon. BS-269
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct RangedStats {
+    acc: i64,
+    range: Range,
+    rof: usize,
+    /// Shots before reloading.
+    shots: usize,
+    /// Seconds to reload.
+    reload: usize,
+    bulk: i64,
+    /// Recoil, one for muscle-powered weapons.
+    rcl: usize,
+}
+
+/// A weapon and the ways it can attack. BS-268
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+)]
+pub struct Weapon {
+    name: String,
+    skill: Skill,
+    attacks: Vec<WeaponAttack>,
+    /// `None` for weapons that cannot parry.
+    parry: Option<Parry>,
+    min_st: usize,
+    /// Requires two hands ("†").
+    two_handed: bool,
+    /// Pounds.
+    weight: f64,
+    /// Dollars.
+    cost: usize,
+    ranged: Option<RangedStats>,
+}
+
+impl Weapon {
+    /// Every weapon in the bundled catalog.
+    pub fn catalog() -> Result<Vec<Self>, serde_json::Error> {
+        Ok(Catalog::load()?.weapons)
+    }
+
+    /// The weapon named `name` in the bundled catalog.
+    pub fn from_catalog(name: &str) -> Option<Self> {
+        Self::catalog()
+            .ok()?
+            .into_iter()
+            .find(|w| w.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Damage of each attack for a wielder with the given basic damage.
+    pub fn damage(&self, base: &BaseDamage) -> Vec<DieLevel> {
+        self.attacks.iter().map(|a| a.damage_for(base)).collect()
+    }
+}
+
+/// A shield, adding its Defense Bonus to all active defenses against attacks from the front.
+/// BS-287
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    derive_getters::Getters,
+    derive_new::new,
+)]
+pub struct Shield {
+    name: String,
+    /// Defense Bonus.
+    db: i64,
+    /// Pounds.
+    weight: f64,
+    /// Dollars.
+    cost: usize,
+    /// Shield bash, using the Shield skill.
+    bash: WeaponAttack,
+}
+
+impl Shield {
+    /// Every shield in the bundled catalog.
+    pub fn catalog() -> Result<Vec<Self>, serde_json::Error> {
+        Ok(Catalog::load()?.shields)
+    }
+
+    /// The shield named `name` in the bundled catalog.
+    pub fn from_catalog(name: &str) -> Option<Self> {
+        Self::catalog()
+            .ok()?
+            .into_iter()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct Catalog {
+    weapons: Vec<Weapon>,
+    shields: Vec<Shield>,
+}
+
+impl Catalog {
+    fn load() -> Result<Self, serde_json::Error> {
+        serde_json::from_str(CATALOG)
+    }
+}